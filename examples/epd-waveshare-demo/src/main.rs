@@ -70,7 +70,7 @@ fn main() -> ! {
 
     // setup mousefood
     let backend = EmbeddedBackendConfig {
-        flush_callback: Box::new(move |display: &mut Display2in9| {
+        flush_callback: Box::new(move |display: &mut Display2in9, _: &FlushContext| {
             epd.update_and_display_frame(&mut spi_device, display.buffer(), &mut delay)
                 .unwrap();
         }),
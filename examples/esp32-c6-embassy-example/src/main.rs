@@ -112,7 +112,7 @@ async fn main(spawner: Spawner) -> ! {
     display.set_rotation(DisplayRotation::Rotate270);
 
     let backend_config = EmbeddedBackendConfig {
-        flush_callback: Box::new(move |d: &mut Display213BlackWhite| {
+        flush_callback: Box::new(move |d: &mut Display213BlackWhite, _: &FlushContext| {
             driver.full_update(d).expect("EPD update failed");
         }),
         ..Default::default()
@@ -120,7 +120,7 @@ fn main() -> ! {
     log::info!("Setup complete, creating the terminal");
 
     // Setup Mousefood and Ratatui
-    let backend = EmbeddedBackend::new(&mut display, Default::default());
+    let backend = EmbeddedBackend::from_display(&mut display);
 
     let mut terminal = Terminal::new(backend).unwrap();
 
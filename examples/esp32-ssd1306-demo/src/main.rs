@@ -0,0 +1,105 @@
+#![no_std]
+#![no_main]
+#![deny(
+    clippy::mem_forget,
+    reason = "mem::forget is generally not safe to do with esp_hal types, especially those \
+    holding buffers for the duration of a data transfer."
+)]
+#![deny(clippy::large_stack_frames)]
+
+extern crate alloc;
+
+use alloc::boxed::Box;
+use esp_hal::clock::CpuClock;
+use esp_hal::delay::Delay;
+use esp_hal::i2c::master::{Config as I2cConfig, I2c};
+use esp_hal::main;
+use esp_hal::time::Rate;
+use mousefood::{EmbeddedBackend, EmbeddedBackendConfig, FlushContext};
+use ratatui::Terminal;
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::Style;
+use ratatui::widgets::{Block, Gauge, Paragraph};
+use ssd1306::{I2CDisplayInterface, Ssd1306, prelude::*};
+
+#[panic_handler]
+fn panic(panic_info: &core::panic::PanicInfo) -> ! {
+    loop {
+        log::error!("{panic_info:?}");
+    }
+}
+
+// This creates a default app-descriptor required by the esp-idf bootloader.
+// For more information see: <https://docs.espressif.com/projects/esp-idf/en/stable/esp32/api-reference/system/app_image_format.html#application-description>
+esp_bootloader_esp_idf::esp_app_desc!();
+
+#[allow(
+    clippy::large_stack_frames,
+    reason = "it's not unusual to allocate larger buffers etc. in main"
+)]
+#[main]
+fn main() -> ! {
+    esp_println::logger::init_logger_from_env();
+    log::info!("Starting Mousefood");
+
+    let config = esp_hal::Config::default().with_cpu_clock(CpuClock::max());
+    let peripherals = esp_hal::init(config);
+
+    esp_alloc::heap_allocator!(size: 64 * 1024);
+
+    // Configure I2C
+    log::info!("Initializing I2C display");
+    let i2c = I2c::new(
+        peripherals.I2C0,
+        I2cConfig::default().with_frequency(Rate::from_khz(400)),
+    )
+    .unwrap()
+    .with_sda(peripherals.GPIO21)
+    .with_scl(peripherals.GPIO22);
+
+    // Configure display
+    let interface = I2CDisplayInterface::new(i2c);
+    let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+        .into_buffered_graphics_mode();
+    display.init().expect("Failed to init display");
+
+    // Setup Mousefood and Ratatui
+    let config = EmbeddedBackendConfig {
+        // Push the driver's framebuffer to the OLED after each frame
+        flush_callback: Box::new(|display: &mut Ssd1306<_, _, _>, _: &FlushContext| {
+            display.flush().unwrap();
+        }),
+        ..EmbeddedBackendConfig::compact()
+    };
+    let backend = EmbeddedBackend::new(&mut display, config);
+    let mut terminal = Terminal::new(backend).unwrap();
+
+    log::info!("Starting render loop");
+
+    let delay = Delay::new();
+    let mut progress = 0;
+    loop {
+        terminal
+            .draw(|frame| {
+                let [top, bottom] = Layout::vertical([Constraint::Fill(1), Constraint::Length(3)])
+                    .areas(frame.area());
+                frame.render_widget(
+                    Paragraph::new("Hello from\nmousefood!")
+                        .centered()
+                        .block(Block::bordered().title("SSD1306")),
+                    top,
+                );
+                frame.render_widget(
+                    Gauge::default()
+                        .block(Block::bordered())
+                        .gauge_style(Style::new().white().on_black())
+                        .percent(progress),
+                    bottom,
+                );
+            })
+            .unwrap();
+
+        progress = (progress + 5) % 105;
+        delay.delay_millis(100);
+    }
+}
@@ -60,11 +60,11 @@ fn main() {
         &config,
     )
     .unwrap();
-    let buffer = Box::leak(Box::new([0_u8; 4096]));
+    let mut buffer = [0_u8; 4096];
     let spi_interface = SpiInterface::new(
         spi_device,
         PinDriver::output(peripherals.pins.gpio16).unwrap(),
-        buffer,
+        &mut buffer,
     );
 
     // Configure display
@@ -105,7 +105,7 @@ fn main() {
     .unwrap();
 
     // Setup Mousefood and Ratatui
-    let backend = EmbeddedBackend::new(&mut display, Default::default());
+    let backend = EmbeddedBackend::from_display(&mut display);
     let mut terminal = Terminal::new(backend).unwrap();
 
     loop {
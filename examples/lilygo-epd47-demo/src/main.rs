@@ -54,7 +54,7 @@ fn main() -> ! {
     let backend = EmbeddedBackendConfig {
         color_theme: theme,
         font_regular: mousefood::fonts::mono_10x20_atlas(),
-        flush_callback: Box::new(move |display: &mut Display| {
+        flush_callback: Box::new(move |display: &mut Display, _: &FlushContext| {
             display
                 .flush(DrawMode::BlackOnWhite)
                 .expect("to flush to the display")
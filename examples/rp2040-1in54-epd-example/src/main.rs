@@ -120,7 +120,7 @@ fn main() -> ! {
     let mut adapter = DisplayAdapter(display);
 
     let backend_config = EmbeddedBackendConfig {
-        flush_callback: Box::new(move |adapter: &mut DisplayAdapter| {
+        flush_callback: Box::new(move |adapter: &mut DisplayAdapter, _: &FlushContext| {
             epd.update_and_display_frame(&mut spi_bus, adapter.0.buffer(), &mut timer)
                 .unwrap();
         }),
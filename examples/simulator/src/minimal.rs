@@ -42,7 +42,7 @@ fn main() -> Result<(), Error> {
 
     let backend_config = EmbeddedBackendConfig {
         // Define how to display newly rendered widgets to the simulator window
-        flush_callback: Box::new(move |display| {
+        flush_callback: Box::new(move |display, _| {
             simulator_window.update(display);
             if simulator_window.events().any(|e| e == SimulatorEvent::Quit) {
                 panic!("simulator window closed");
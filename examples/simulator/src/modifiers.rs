@@ -48,7 +48,7 @@ fn main() -> Result<(), Error> {
     let events_cb = events.clone();
 
     let backend_config = EmbeddedBackendConfig {
-        flush_callback: Box::new(move |display| {
+        flush_callback: Box::new(move |display, _| {
             simulator_window.update(display);
             let mut ev = events_cb.borrow_mut();
             ev.clear();
@@ -94,7 +94,7 @@ async fn main(_spawner: Spawner) {
         &mut display,
         EmbeddedBackendConfig {
             // Push framebuffer to OLED after each draw
-            flush_callback: alloc::boxed::Box::new(|d| {
+            flush_callback: alloc::boxed::Box::new(|d, _| {
                 d.flush().unwrap();
             }),
 
@@ -0,0 +1,57 @@
+//! Heap allocation instrumentation.
+//!
+//! Wrap your global allocator in [`CountingAllocator`] to let the backend report
+//! how many heap allocations each frame performed via
+//! [`EmbeddedBackend::frame_allocations`](crate::EmbeddedBackend::frame_allocations).
+//!
+//! # Examples
+//!
+//! ```rust
+//! use mousefood::alloc_stats::CountingAllocator;
+//!
+//! #[global_allocator]
+//! static ALLOCATOR: CountingAllocator<std::alloc::System> =
+//!     CountingAllocator::new(std::alloc::System);
+//! ```
+
+use core::alloc::{GlobalAlloc, Layout};
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+/// A [`GlobalAlloc`] wrapper counting every allocation and reallocation.
+pub struct CountingAllocator<A>(A);
+
+impl<A> CountingAllocator<A> {
+    /// Wraps the given allocator.
+    pub const fn new(allocator: A) -> Self {
+        Self(allocator)
+    }
+}
+
+// SAFETY: all calls are forwarded unchanged to the wrapped allocator.
+unsafe impl<A: GlobalAlloc> GlobalAlloc for CountingAllocator<A> {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { self.0.alloc(layout) }
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { self.0.alloc_zeroed(layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { self.0.realloc(ptr, layout, new_size) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { self.0.dealloc(ptr, layout) }
+    }
+}
+
+/// Returns the number of allocations performed through [`CountingAllocator`] so far.
+pub fn allocations() -> usize {
+    ALLOCATIONS.load(Ordering::Relaxed)
+}
@@ -1,23 +1,33 @@
+use crate::blocks::block_element_rect;
+use crate::capabilities::DisplayCapabilities;
 use crate::colors::*;
-use crate::cursor::{Cursor, CursorConfig};
+use crate::cursor::{Cursor, CursorConfig, CursorFn, CursorTarget};
 use crate::default_font;
+use crate::dither::{ConversionQuality, DitherTarget, Dithering, channel_bits, truncate_channels};
 use crate::error::Result;
+use crate::scale::{Rotation, Scaled};
 use alloc::boxed::Box;
 #[cfg(feature = "blink")]
 use alloc::collections::BTreeMap;
+use alloc::string::String;
 use core::marker::PhantomData;
+use core::time::Duration;
 use embedded_graphics::Drawable;
-use embedded_graphics::draw_target::DrawTarget;
+use embedded_graphics::draw_target::{DrawTarget, DrawTargetExt};
 use embedded_graphics::geometry::{self, Dimensions};
 use embedded_graphics::mono_font::{MonoFont, MonoTextStyleBuilder};
-use embedded_graphics::pixelcolor::{PixelColor, Rgb888};
+use embedded_graphics::pixelcolor::{BinaryColor, PixelColor, Rgb888};
+use embedded_graphics::primitives::Rectangle;
 use embedded_graphics::text::Text;
+use embedded_graphics::text::renderer::TextRenderer;
+use embedded_graphics::transform::Transform;
 use ratatui_core::backend::{Backend, ClearType};
 use ratatui_core::layout;
 use ratatui_core::style;
 
 /// Terminal alignment
 #[derive(Clone, Copy)]
+#[non_exhaustive]
 pub enum TerminalAlignment {
     /// Alignment with the start of the terminal: left or top.
     Start,
@@ -25,10 +35,358 @@ pub enum TerminalAlignment {
     Center,
     /// Alignment with the end of the terminal: right or bottom.
     End,
+    /// Shift from the start of the terminal by the given number of pixels.
+    ///
+    /// Calibrates the view on panels whose active area is offset. A positive offset
+    /// shrinks the grid like a margin would. Cells pushed past the display edge are
+    /// not drawn.
+    Offset(i32),
+}
+
+/// How blink modifiers are rendered on displays too slow to blink.
+///
+/// E-paper panels can't toggle visibility a few times per second, but blinking
+/// text usually asks for attention. These modes replace blinking with a static
+/// effect that is drawn once and never toggles.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum SlowBlinkMode {
+    /// Blink normally (requires the `blink` feature, otherwise blink is ignored).
+    #[default]
+    None,
+    /// Swap foreground and background colors of blinking cells.
+    Invert,
+    /// Draw an outline in the foreground color around blinking cells.
+    Border,
+}
+
+/// Which text modifiers take effect, depending on what the display can show.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum ModifierProfile {
+    /// [`EPaper`](Self::EPaper) on e-paper color types from the `epd-*` features,
+    /// [`Full`](Self::Full) otherwise.
+    #[default]
+    Auto,
+    /// All modifiers take effect.
+    Full,
+    /// Only modifiers that stay meaningful in black and white take effect.
+    ///
+    /// `BOLD` uses the bold font, or draws the glyph twice one pixel apart without
+    /// one. `REVERSED` swaps the colors and `UNDERLINED` draws a line at the bottom
+    /// of the cell. `DIM` and the blink modifiers are ignored.
+    EPaper,
+}
+
+/// Visible shape of the display.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum ClipShape {
+    /// The whole rectangular display is visible.
+    #[default]
+    Rectangle,
+    /// Only the circle inscribed in the display is visible, as on round panels.
+    ///
+    /// Cells whose center lies outside the circle are not drawn.
+    Circle,
+}
+
+/// How the framebuffer is transferred to the display on flush.
+#[cfg(feature = "framebuffer")]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum FlushStrategy {
+    /// Push the whole buffer with a single [`DrawTarget::fill_contiguous`] call.
+    #[default]
+    FillContiguous,
+    /// Push every pixel through [`DrawTarget::draw_iter`].
+    ///
+    /// For minimal draw targets that don't implement `fill_contiguous` efficiently
+    /// or at all.
+    DrawIter,
+}
+
+impl TerminalAlignment {
+    /// Offset in pixels for `extra` pixels left over by the grid.
+    fn offset(self, extra: u16) -> i32 {
+        match self {
+            TerminalAlignment::Start => 0,
+            TerminalAlignment::Center => (extra / 2) as i32,
+            TerminalAlignment::End => extra as i32,
+            TerminalAlignment::Offset(offset) => offset,
+        }
+    }
+
+    /// Pixels at the start of the display the grid doesn't cover.
+    fn inset(self) -> u16 {
+        match self {
+            TerminalAlignment::Offset(offset) => offset.clamp(0, u16::MAX.into()) as u16,
+            _ => 0,
+        }
+    }
+}
+
+/// Pixels of the row below cells whose underline lies below the cell, saved when an
+/// underlined cell is drawn so they can be restored once it's drawn without one.
+///
+/// Storage for the whole grid is allocated up front, so redrawing underlined cells
+/// doesn't touch the heap.
+#[cfg(feature = "framebuffer")]
+struct UnderlineOverflow<C> {
+    columns: u16,
+    /// Size of the strip below a cell that its underline covers.
+    strip: geometry::Size,
+    /// Area saved for each cell, row by row.
+    areas: alloc::vec::Vec<Option<Rectangle>>,
+    /// Pixels of the saved areas, one strip for each cell.
+    pixels: alloc::vec::Vec<C>,
+}
+
+#[cfg(feature = "framebuffer")]
+impl<C> Default for UnderlineOverflow<C> {
+    fn default() -> Self {
+        Self {
+            columns: 0,
+            strip: geometry::Size::zero(),
+            areas: alloc::vec::Vec::new(),
+            pixels: alloc::vec::Vec::new(),
+        }
+    }
+}
+
+#[cfg(feature = "framebuffer")]
+impl<C: Copy> UnderlineOverflow<C> {
+    /// Allocates storage for a grid of `columns_rows`, or none if `strip` is empty.
+    fn new(columns_rows: layout::Size, strip: geometry::Size, fill: C) -> Self {
+        if strip.width * strip.height == 0 {
+            return Self::default();
+        }
+        let cells = columns_rows.area() as usize;
+        Self {
+            columns: columns_rows.width,
+            strip,
+            areas: alloc::vec![None; cells],
+            pixels: alloc::vec![fill; cells * (strip.width * strip.height) as usize],
+        }
+    }
+
+    /// Size of the strip below a cell that its underline covers, zero if underlines
+    /// stay inside the cell.
+    fn strip(&self) -> geometry::Size {
+        self.strip
+    }
+
+    fn index(&self, x: u16, y: u16) -> Option<usize> {
+        let idx = y as usize * self.columns as usize + x as usize;
+        (x < self.columns && idx < self.areas.len()).then_some(idx)
+    }
+
+    fn strip_len(&self) -> usize {
+        (self.strip.width * self.strip.height) as usize
+    }
+
+    fn remove(&mut self, x: u16, y: u16) {
+        if let Some(idx) = self.index(x, y) {
+            self.areas[idx] = None;
+        }
+    }
+
+    /// Removes the area saved for the cell at `x`, `y` and returns it with its pixels.
+    fn take(&mut self, x: u16, y: u16) -> Option<(Rectangle, &[C])> {
+        let idx = self.index(x, y)?;
+        let area = self.areas[idx].take()?;
+        let start = idx * self.strip_len();
+        let len = (area.size.width * area.size.height) as usize;
+        Some((area, &self.pixels[start..start + len]))
+    }
+
+    /// Saves `pixels` of `area`, which must fit in a strip, for the cell at `x`, `y`.
+    fn save(&mut self, x: u16, y: u16, area: Rectangle, pixels: impl IntoIterator<Item = C>) {
+        let Some(idx) = self.index(x, y) else {
+            return;
+        };
+        let len = self.strip_len();
+        for (saved, pixel) in self.pixels[idx * len..(idx + 1) * len]
+            .iter_mut()
+            .zip(pixels)
+        {
+            *saved = pixel;
+        }
+        self.areas[idx] = Some(area);
+    }
+
+    fn clear(&mut self) {
+        self.areas.fill(None);
+    }
+
+    /// Moves the areas saved in `region` up (or down) by `lines` rows of
+    /// `cell_height` pixels.
+    ///
+    /// Overflow that leaves or enters the region with its cell is dropped, as the
+    /// row below it doesn't move along.
+    fn scroll(&mut self, region: core::ops::Range<u16>, lines: u16, up: bool, cell_height: u32) {
+        if self.areas.is_empty() {
+            return;
+        }
+        let columns = self.columns as usize;
+        let len = self.strip_len();
+        let dy = (u32::from(lines) * cell_height) as i32;
+        let dy = if up { -dy } else { dy };
+        // Rows are visited so that each source row is read before it's overwritten.
+        for i in 0..region.len() as u16 {
+            let to = if up {
+                region.start + i
+            } else {
+                region.end - 1 - i
+            };
+            let from = if up {
+                to.checked_add(lines)
+            } else {
+                to.checked_sub(lines)
+            }
+            .filter(|from| region.contains(from) && from + 1 < region.end && to + 1 < region.end);
+            for x in 0..columns {
+                let to_idx = to as usize * columns + x;
+                self.areas[to_idx] = from.and_then(|from| {
+                    let from_idx = from as usize * columns + x;
+                    let area = self.areas[from_idx]?;
+                    self.pixels
+                        .copy_within(from_idx * len..(from_idx + 1) * len, to_idx * len);
+                    Some(area.translate(geometry::Point::new(0, dy)))
+                });
+            }
+        }
+        if let Some(above) = region.start.checked_sub(1) {
+            let start = above as usize * columns;
+            self.areas[start..start + columns].fill(None);
+        }
+    }
+}
+
+/// Computes the terminal grid size and its pixel offset for a display of `pixels`,
+/// inset by `margin` (top, right, bottom, left).
+fn grid_geometry(
+    origin: geometry::Point,
+    pixels: layout::Size,
+    margin: (u16, u16, u16, u16),
+    char_size: geometry::Size,
+    horizontal_alignment: TerminalAlignment,
+    vertical_alignment: TerminalAlignment,
+) -> (layout::Size, geometry::Point) {
+    let (top, right, bottom, left) = margin;
+    let origin = origin + geometry::Point::new(left.into(), top.into());
+    let pixels = layout::Size {
+        width: pixels
+            .width
+            .saturating_sub(left + right)
+            .saturating_sub(horizontal_alignment.inset()),
+        height: pixels
+            .height
+            .saturating_sub(top + bottom)
+            .saturating_sub(vertical_alignment.inset()),
+    };
+    let char_w = char_size.width as u16;
+    let char_h = char_size.height as u16;
+    let columns_rows = layout::Size {
+        width: pixels.width / char_w,
+        height: pixels.height / char_h,
+    };
+    let char_offset = origin
+        + geometry::Point::new(
+            horizontal_alignment.offset(pixels.width % char_w),
+            vertical_alignment.offset(pixels.height % char_h),
+        );
+    (columns_rows, char_offset)
+}
+
+/// Returns the size of a terminal cell for a font of `char_size`.
+fn cell_size(
+    char_size: geometry::Size,
+    line_height_percent: u16,
+    letter_spacing: u16,
+    line_spacing: u16,
+) -> geometry::Size {
+    let height = char_size.height * u32::from(line_height_percent) / 100;
+    geometry::Size::new(
+        char_size.width + u32::from(letter_spacing),
+        height.max(1) + u32::from(line_spacing),
+    )
+}
+
+/// Returns the size of the unscaled output for a display of `pixels`.
+fn scaled_size(pixels: layout::Size, scale: u32) -> layout::Size {
+    layout::Size {
+        width: pixels.width / scale as u16,
+        height: pixels.height / scale as u16,
+    }
+}
+
+/// Returns the size of a display of `pixels` as seen by the terminal.
+fn rotated_size(pixels: layout::Size, rotation: Rotation) -> layout::Size {
+    if rotation.swaps_axes() {
+        layout::Size::new(pixels.height, pixels.width)
+    } else {
+        pixels
+    }
+}
+
+/// Returns the framebuffer area for a display with `bounding_box`, `scale` and
+/// `rotation`.
+#[cfg(feature = "framebuffer")]
+fn scaled_bounds(bounding_box: Rectangle, scale: u8, rotation: Rotation) -> Rectangle {
+    Rectangle::new(
+        bounding_box.top_left,
+        rotation.rotate_size(bounding_box.size) / u32::from(scale.max(1)),
+    )
+}
+
+/// Splits `area` into horizontal strips of at most `height` rows.
+#[cfg(feature = "framebuffer")]
+fn row_chunks(area: Rectangle, height: u32) -> impl Iterator<Item = Rectangle> {
+    let height = height.max(1);
+    (0..area.size.height)
+        .step_by(height as usize)
+        .map(move |y| {
+            Rectangle::new(
+                area.top_left + geometry::Point::new(0, y as i32),
+                geometry::Size::new(area.size.width, height.min(area.size.height - y)),
+            )
+        })
+}
+
+/// Moves the rows of `cells` by `shift` entries, up (towards the start) or down,
+/// filling the vacated entries with `fill`.
+#[cfg(any(feature = "framebuffer", feature = "cell-cache"))]
+fn shift_cells<T: Clone>(cells: &mut [T], shift: usize, up: bool, fill: &T) {
+    let len = cells.len();
+    if up {
+        cells.rotate_left(shift);
+        cells[len - shift..].fill(fill.clone());
+    } else {
+        cells.rotate_right(shift);
+        cells[..shift].fill(fill.clone());
+    }
+}
+
+/// Returns whether `font` has a glyph for `ch`, rather than drawing its replacement
+/// glyph.
+fn font_covers(font: &MonoFont<'_>, ch: char) -> bool {
+    // The replacement glyph of the embedded-graphics fonts is `?`.
+    ch == '?' || font.glyph_mapping.index(ch) != font.glyph_mapping.index(char::MAX)
+}
+
+/// Draws `text` to `target`, clipped to `clip` if given.
+fn draw_text<T, S>(text: &Text<'_, S>, target: &mut T, clip: Option<&Rectangle>) -> Result<()>
+where
+    T: DrawTarget,
+    T::Error: core::fmt::Debug + Send + Sync + 'static,
+    S: TextRenderer<Color = T::Color>,
+{
+    match clip {
+        Some(area) => text.draw(&mut target.clipped(area)),
+        None => text.draw(target),
+    }
+    .map(|_| ())
+    .map_err(crate::error::Error::draw)
 }
 
 /// Timing parameters for a single blink pattern.
-#[cfg(feature = "blink")]
 #[derive(Clone, Copy)]
 pub struct BlinkTiming {
     /// How many times per second the element toggles.
@@ -37,32 +395,79 @@ pub struct BlinkTiming {
     /// e.g. 15 means hidden 15% of each cycle.
     pub duty_percent: u16,
     hidden: bool,
+    intensity: u8,
 }
 
-#[cfg(feature = "blink")]
 impl BlinkTiming {
+    /// Creates a timing that toggles `blinks_per_sec` times per second and stays
+    /// hidden for `duty_percent` of each cycle.
+    pub const fn new(blinks_per_sec: u16, duty_percent: u16) -> Self {
+        Self {
+            blinks_per_sec,
+            duty_percent,
+            hidden: false,
+            intensity: 255,
+        }
+    }
+
     /// Returns `true` if the element is currently hidden.
     pub fn is_hidden(&self) -> bool {
         self.hidden
     }
 
+    /// Returns the visibility of the element for `BlinkStyle::Fade`, from 0
+    /// (faded out) to 255 (fully visible).
+    ///
+    /// Falls from 255 at the start of each cycle to 0 halfway through and rises again.
+    pub fn intensity(&self) -> u8 {
+        self.intensity
+    }
+
     /// Update hidden state based on the current frame count and FPS.
+    #[cfg(feature = "blink")]
     fn update(&mut self, frame_count: u16, fps: u16) {
+        self.hidden = false;
+        self.intensity = 255;
         if self.blinks_per_sec == 0 || fps == 0 {
-            self.hidden = false;
             return;
         }
         let cycle_len = fps / self.blinks_per_sec;
         if cycle_len == 0 {
-            self.hidden = false;
             return;
         }
         let pos = frame_count % cycle_len;
         let hidden_frames = ((self.duty_percent * cycle_len + 50) / 100).max(1);
         self.hidden = pos >= cycle_len - hidden_frames;
+        self.intensity = ((2 * pos).abs_diff(cycle_len) as u32 * 255 / cycle_len as u32) as u8;
+    }
+
+    /// Visibility used to detect changes, depending on how the blink is rendered.
+    #[cfg(feature = "blink")]
+    fn level(&self, style: BlinkStyle) -> u8 {
+        match style {
+            BlinkStyle::Fade => self.intensity,
+            _ if self.hidden => 0,
+            _ => 255,
+        }
     }
 }
 
+/// How blinking text is rendered during the hidden phase of a blink cycle.
+#[cfg(feature = "blink")]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[non_exhaustive]
+pub enum BlinkStyle {
+    /// Draw the foreground in the background color, hiding the text.
+    #[default]
+    Hide,
+    /// Dim the foreground, for a gentler breathing effect.
+    Dim,
+    /// Fade the foreground into the background and back over each cycle, following
+    /// [`BlinkTiming::intensity`]. Meant for true-color displays; monochrome displays
+    /// hide the text instead.
+    Fade,
+}
+
 /// Blink configuration for text modifiers and cursor.
 ///
 /// Owns all blink state. Call [`BlinkConfig::tick`] once per frame to advance.
@@ -75,7 +480,9 @@ pub struct BlinkConfig {
     pub slow: BlinkTiming,
     /// Timing for [`Modifier::RAPID_BLINK`].
     pub fast: BlinkTiming,
-    prev_state: (bool, bool),
+    /// How blinking text looks during the hidden phase.
+    pub style: BlinkStyle,
+    prev_state: (u8, u8),
 }
 
 #[cfg(feature = "blink")]
@@ -84,7 +491,12 @@ impl BlinkConfig {
     pub fn tick(&mut self, frame_count: u16) -> bool {
         self.slow.update(frame_count, self.fps);
         self.fast.update(frame_count, self.fps);
-        let state = (self.slow.hidden, self.fast.hidden);
+        self.take_toggled()
+    }
+
+    /// Returns `true` if visibility changed since the last tick.
+    fn take_toggled(&mut self) -> bool {
+        let state = (self.slow.level(self.style), self.fast.level(self.style));
         let toggled = state != self.prev_state;
         self.prev_state = state;
         toggled
@@ -100,31 +512,79 @@ impl Default for BlinkConfig {
                 blinks_per_sec: 1,
                 duty_percent: 15,
                 hidden: false,
+                intensity: 255,
             },
             fast: BlinkTiming {
                 blinks_per_sec: 3,
                 duty_percent: 50,
                 hidden: false,
+                intensity: 255,
             },
-            prev_state: (false, false),
+            style: BlinkStyle::Hide,
+            prev_state: (255, 255),
         }
     }
 }
 
+/// Callback that starts (`true`) or ends (`false`) the screensaver.
+pub type ScreensaverFn<D> = Box<dyn FnMut(&mut D, bool)>;
+
+/// Callback fired after each flush, see [`EmbeddedBackendConfig::flush_callback`].
+pub type FlushFn<D> = Box<dyn FnMut(&mut D, &FlushContext)>;
+
+/// What changed on the display since the previous flush.
+///
+/// Lets the flush callback pick the kind of update, e.g. a slow full refresh of an
+/// e-paper panel to clear ghosting versus a fast partial refresh of `dirty_rect`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FlushContext {
+    /// Whether the whole screen was redrawn, i.e. on the first flush and after a clear.
+    pub full_refresh: bool,
+    /// Region of the display written by this flush, in display pixels.
+    ///
+    /// Covers the cursor if it's drawn before the callback, see
+    /// [`EmbeddedBackendConfig::cursor_before_callback`]. Empty if nothing else
+    /// changed. Without the `framebuffer` feature, changes aren't tracked and this
+    /// covers the whole display.
+    pub dirty_rect: Rectangle,
+}
+
+/// Driver callback that writes one row of framebuffer pixels to the display area.
+///
+/// The pixels are the framebuffer's own storage, so no per-pixel conversion or
+/// [`DrawTarget`] call is involved. For [`Rgb565`] the raw `u16` words are
+/// available through [`IntoStorage`].
+///
+/// [`Rgb565`]: embedded_graphics::pixelcolor::Rgb565
+/// [`IntoStorage`]: embedded_graphics::pixelcolor::IntoStorage
+#[cfg(feature = "framebuffer")]
+pub type RowFlushFn<D, C> =
+    Box<dyn FnMut(&mut D, Rectangle, &[C]) -> core::result::Result<(), <D as DrawTarget>::Error>>;
+
 /// Embedded backend configuration.
 pub struct EmbeddedBackendConfig<D, C>
 where
     D: DrawTarget<Color = C>,
     C: PixelColor,
 {
-    /// Callback fired after each buffer flush.
-    pub flush_callback: Box<dyn FnMut(&mut D)>,
+    /// Callback fired after each buffer flush, with what the flush changed.
+    pub flush_callback: FlushFn<D>,
     /// Regular font.
     pub font_regular: MonoFont<'static>,
     /// Bold font.
     pub font_bold: Option<MonoFont<'static>>,
     /// Italic font.
     pub font_italic: Option<MonoFont<'static>>,
+    /// Font for text that is both bold and italic.
+    ///
+    /// Without it, such text uses the bold font, or the italic font if there is no
+    /// bold font.
+    pub font_bold_italic: Option<MonoFont<'static>>,
+    /// Font for symbols that the font selected for a cell has no glyph for.
+    ///
+    /// Useful to combine a small ASCII font with a font that covers e.g. box-drawing
+    /// characters.
+    pub fallback_font: Option<MonoFont<'static>>,
 
     /// Determines how the view is vertically aligned when the display height
     /// is not an exact multiple of the font height.
@@ -134,15 +594,251 @@ where
     /// is not an exact multiple of the font width.
     pub horizontal_alignment: TerminalAlignment,
 
+    /// Pixels kept free at the top, right, bottom and left edges of the display.
+    ///
+    /// Shrinks the area available to the terminal, e.g. to keep text away from a
+    /// bezel or rounded corners. Alignment applies within the remaining area.
+    pub margin: (u16, u16, u16, u16),
+
+    /// Height of a terminal row as a percentage of the font height.
+    ///
+    /// Values above `100` add spacing between rows. Glyphs are centered vertically
+    /// in the taller cells. Defaults to `100`.
+    pub line_height_percent: u16,
+
+    /// Pixels added to the right of each cell, on top of the font's own spacing.
+    ///
+    /// Cell backgrounds and underlines cover the added pixels. Defaults to `0`.
+    pub letter_spacing: u16,
+
+    /// Pixels added below each row, after applying
+    /// [`line_height_percent`](Self::line_height_percent).
+    ///
+    /// Unlike the line height, glyphs stay at the top of the row. Defaults to `0`.
+    pub line_spacing: u16,
+
     /// Color theme that maps Ratatui colors to display pixels.
     pub color_theme: ColorTheme,
 
     /// Cursor appearance and blink behavior.
     pub cursor: CursorConfig,
 
+    /// Draws the cursor when its style is [`CursorStyle::Custom`].
+    ///
+    /// [`CursorStyle::Custom`]: crate::CursorStyle::Custom
+    pub cursor_renderer: Option<CursorFn<D>>,
+
+    /// Color used for underlines when the cell doesn't specify one.
+    ///
+    /// `None` draws underlines in the foreground color. With the `underline-color`
+    /// feature enabled, a cell's own `underline_color` takes precedence.
+    pub default_underline_color: Option<Rgb888>,
+
+    /// Color used for the strikethrough line of crossed out cells.
+    ///
+    /// `None` draws the line in the foreground color.
+    #[cfg(feature = "strikethrough-color")]
+    pub strikethrough_color: Option<Rgb888>,
+
+    /// Whether underlines are drawn as a line at the bottom of the cell instead of
+    /// by the font.
+    ///
+    /// Gives the same underline regardless of the font's underline metrics.
+    pub manual_underline: bool,
+
+    /// Thickness in pixels of underlines drawn with `manual_underline`.
+    pub underline_thickness: u32,
+
+    /// Whether underlines are drawn as two 1px lines separated by a 1px gap at the
+    /// bottom of the cell.
+    ///
+    /// Takes precedence over `manual_underline`.
+    pub double_underline: bool,
+
+    /// Whether the cursor is drawn before `flush_callback` runs.
+    ///
+    /// When `true` (default), the cursor is part of the frame handed to the callback,
+    /// which is what displays that transfer inside the callback (e.g. e-paper) need.
+    /// When `false`, the cursor is drawn after the callback returns, so a callback
+    /// issuing a partial refresh only pushes ratatui content and the cursor shows up
+    /// with the next transfer.
+    pub cursor_before_callback: bool,
+
+    /// Factor the rendered output is scaled up by, e.g. `2` to draw every font pixel
+    /// as a block of 2×2 display pixels on high-DPI panels.
+    ///
+    /// The terminal grid shrinks accordingly. `0` is treated as `1`.
+    pub scale: u8,
+
+    /// Rotation of the terminal on the display, applied in software.
+    ///
+    /// The terminal grid follows the rotated display, e.g. a 128×64 display rotated by
+    /// 90° has the grid of a 64×128 display. Hardware scrolling and
+    /// [`row_flush_callback`](Self::row_flush_callback) aren't used while rotated.
+    pub rotation: Rotation,
+
+    /// Draw Unicode block elements (`▁▂▃▄▅▆▇█`, `▏▎▍▌▋▊▉`, …) as rectangles
+    /// instead of font glyphs.
+    ///
+    /// Ratatui's `Gauge`, `BarChart` and `Sparkline` use these to render sub-cell
+    /// fills. Drawing them natively fills the exact fraction of the cell, even with
+    /// fonts that lack the glyphs.
+    ///
+    /// `LineGauge` only fills whole cells, so it has no sub-cell fill to smooth out.
+    /// Its bar is still drawn natively when `filled_symbol` is a block element, e.g.
+    /// `▄` for a bar covering the lower half of the row.
+    pub native_block_elements: bool,
+
+    /// Static replacement for [`Modifier::SLOW_BLINK`] and [`Modifier::RAPID_BLINK`]
+    /// on displays that can't blink, such as e-paper.
+    ///
+    /// [`Modifier::SLOW_BLINK`]: ratatui_core::style::Modifier::SLOW_BLINK
+    /// [`Modifier::RAPID_BLINK`]: ratatui_core::style::Modifier::RAPID_BLINK
+    pub slow_display_blink_mode: SlowBlinkMode,
+
+    /// Which text modifiers take effect.
+    pub modifier_profile: ModifierProfile,
+
+    /// Clip glyphs and their decorations to the bounds of their cell.
+    ///
+    /// Descenders extend below the cell if the font is taller than the line, e.g.
+    /// with a [`line_height_percent`](Self::line_height_percent) below 100, and some
+    /// fonts place the underline below the character cell. Either then overwrites
+    /// the top of the row below. Clipping prevents these cross-row artifacts at the
+    /// cost of cutting off the overflowing pixels. Glyphs that fit their cell are
+    /// drawn as is.
+    pub clip_to_cell: bool,
+
+    /// Skip cells that don't fit entirely on the display.
+    ///
+    /// Cells outside the grid, e.g. from a stray draw at the edge, would otherwise
+    /// be drawn partly past the display bounds, which some draw targets reject.
+    /// Defaults to `true`.
+    pub clip_out_of_bounds: bool,
+
+    /// Visible shape of the display. Cells outside of it are skipped.
+    pub clip_shape: ClipShape,
+
+    /// Dithering of cell colors, for displays that can only show black and white.
+    pub dithering: Dithering,
+
+    /// How cell colors are reduced to displays with fewer bits per channel, such as
+    /// `Rgb565`.
+    pub conversion_quality: ConversionQuality,
+
+    /// Lookup table applied to each RGB component of cell colors before they are
+    /// converted to the display color.
+    ///
+    /// A gamma-correcting table keeps perceived brightness and gradients intact when
+    /// colors are reduced to e.g. `Rgb565`. `None` converts colors unchanged.
+    pub gamma_lut: Option<&'static [u8; 256]>,
+
+    /// Snap cell colors to the nearest color of the theme palette.
+    ///
+    /// Useful for displays with a small fixed palette, where arbitrary RGB colors
+    /// would otherwise be mapped by the display color conversion.
+    pub quantize: bool,
+
+    /// Pairs of colors, where cells using the first color are drawn with the second.
+    ///
+    /// Applied before colors are resolved, e.g. to replace a color that is hard to
+    /// read on a particular panel. `None` leaves colors unchanged.
+    pub color_remap: Option<&'static [(style::Color, style::Color)]>,
+
+    /// Maximum number of cells a single [`Backend::draw`] call may draw.
+    ///
+    /// Exceeding it returns [`Error::CellLimit`](crate::error::Error::CellLimit)
+    /// instead of drawing the remaining cells, to catch runaway rendering. `None`
+    /// doesn't limit draws.
+    pub max_cells_per_draw: Option<usize>,
+
+    /// Number of rendered glyphs to keep, keyed by symbol, colors and modifiers.
+    ///
+    /// Glyphs found in the cache are copied to the display instead of being drawn
+    /// with the font again, at the cost of storing their pixels. Glyphs missing from
+    /// the cache allocate when they're added. `0` disables the cache. Defaults to
+    /// `64`.
+    #[cfg(feature = "glyph-cache")]
+    pub glyph_cache_size: usize,
+
+    /// Number of frames without [`note_activity`](EmbeddedBackend::note_activity)
+    /// after which the screensaver starts. `None` disables the screensaver.
+    ///
+    /// A frame is counted on each [`flush`](Backend::flush).
+    pub screensaver_timeout: Option<u32>,
+
+    /// Called with `true` when the screensaver starts and with `false` when activity
+    /// ends it, e.g. to dim the backlight or blank the display.
+    pub screensaver_callback: ScreensaverFn<D>,
+
+    /// Time a frame may take before
+    /// [`watchdog_feed_callback`](Self::watchdog_feed_callback) is called.
+    ///
+    /// Long draws and flushes check [`frame_clock`](Self::frame_clock) between rows
+    /// and feed the watchdog each time the budget has elapsed, so a slow render
+    /// doesn't reset the device. With a budget, the framebuffer is transferred in
+    /// chunks of one character row. `None` disables the checks.
+    pub frame_budget: Option<Duration>,
+
+    /// Monotonic clock used to measure [`frame_budget`](Self::frame_budget).
+    pub frame_clock: Option<Box<dyn FnMut() -> Duration>>,
+
+    /// Called when a frame exceeds [`frame_budget`](Self::frame_budget), e.g. to
+    /// feed the hardware watchdog.
+    pub watchdog_feed_callback: Option<Box<dyn FnMut()>>,
+
+    /// Caps how often [`flush`](Backend::flush) updates the display.
+    ///
+    /// Without a delay, flushes that come too early return without writing to the
+    /// display, and their changes go out with the next flush or with
+    /// [`EmbeddedBackend::flush_pending`]. With the `blink`
+    /// feature, [`BlinkConfig::fps`] is set to the limiter's frame rate, so blink
+    /// timing follows the actual frame rate. `None` doesn't limit flushes.
+    #[cfg(feature = "frame-limiter")]
+    pub frame_limiter: Option<crate::FrameLimiter>,
+
     /// Blink timing for text modifiers and cursor.
     #[cfg(feature = "blink")]
     pub blink: BlinkConfig,
+
+    /// How the framebuffer is transferred to the display.
+    #[cfg(feature = "framebuffer")]
+    pub flush_strategy: FlushStrategy,
+
+    /// Writes framebuffer rows straight to the display on flush instead of going
+    /// through `flush_strategy`.
+    ///
+    /// Useful for SPI panels whose drivers take a slice of raw pixels. Not used
+    /// while the display is inverted, `scale` is above 1 or `rotation` is set.
+    #[cfg(feature = "framebuffer")]
+    pub row_flush_callback: Option<RowFlushFn<D, C>>,
+
+    /// How many times a failed framebuffer transfer is retried before `flush`
+    /// returns an error.
+    #[cfg(feature = "framebuffer")]
+    pub flush_retries: u8,
+
+    /// Called before each retry with the retry number (starting at 1), e.g. to wait
+    /// with a backoff before the next transfer.
+    #[cfg(feature = "framebuffer")]
+    pub flush_retry_delay: Option<Box<dyn FnMut(u8)>>,
+
+    /// Draw into a back buffer while the front buffer holds the frame last flushed,
+    /// and swap the two after each flush.
+    ///
+    /// Only rows that differ between the back and front buffers are transferred,
+    /// so redrawn content that didn't change is skipped. The rows passed to the
+    /// display aren't written again until the next flush, so a
+    /// [`row_flush_callback`](Self::row_flush_callback) may keep transferring them,
+    /// e.g. by DMA, while the next frame is drawn.
+    ///
+    /// Doubles the framebuffer memory. The front buffer is allocated on the heap;
+    /// use [`EmbeddedBackend::with_double_buffer`] to provide its storage instead.
+    #[cfg(feature = "framebuffer")]
+    pub double_buffer: bool,
+
+    /// Hardware features of the display that mousefood may use.
+    pub capabilities: DisplayCapabilities<D>,
 }
 
 impl<D, C> Default for EmbeddedBackendConfig<D, C>
@@ -152,20 +848,196 @@ where
 {
     fn default() -> Self {
         Self {
-            flush_callback: Box::new(|_| {}),
+            flush_callback: Box::new(|_, _| {}),
             font_regular: default_font::get_regular(),
             font_bold: None,
             font_italic: None,
+            font_bold_italic: None,
+            fallback_font: None,
             vertical_alignment: TerminalAlignment::Start,
             horizontal_alignment: TerminalAlignment::Start,
+            margin: (0, 0, 0, 0),
+            line_height_percent: 100,
+            letter_spacing: 0,
+            line_spacing: 0,
             color_theme: ColorTheme::default(),
             cursor: CursorConfig::default(),
+            default_underline_color: None,
+            #[cfg(feature = "strikethrough-color")]
+            strikethrough_color: None,
+            manual_underline: false,
+            underline_thickness: 1,
+            double_underline: false,
+            cursor_before_callback: true,
+            scale: 1,
+            rotation: Rotation::Deg0,
+            native_block_elements: false,
+            slow_display_blink_mode: SlowBlinkMode::None,
+            modifier_profile: ModifierProfile::Auto,
+            clip_to_cell: false,
+            clip_out_of_bounds: true,
+            clip_shape: ClipShape::default(),
             #[cfg(feature = "blink")]
             blink: BlinkConfig::default(),
+            #[cfg(feature = "framebuffer")]
+            flush_strategy: FlushStrategy::default(),
+            #[cfg(feature = "framebuffer")]
+            row_flush_callback: None,
+            #[cfg(feature = "framebuffer")]
+            flush_retries: 0,
+            #[cfg(feature = "framebuffer")]
+            flush_retry_delay: None,
+            #[cfg(feature = "framebuffer")]
+            double_buffer: false,
+            capabilities: DisplayCapabilities::default(),
+            dithering: Dithering::default(),
+            conversion_quality: ConversionQuality::default(),
+            gamma_lut: None,
+            quantize: false,
+            color_remap: None,
+            max_cells_per_draw: None,
+            #[cfg(feature = "glyph-cache")]
+            glyph_cache_size: 64,
+            screensaver_timeout: None,
+            screensaver_callback: Box::new(|_, _| {}),
+            cursor_renderer: None,
+            frame_budget: None,
+            frame_clock: None,
+            watchdog_feed_callback: None,
+            #[cfg(feature = "frame-limiter")]
+            frame_limiter: None,
+        }
+    }
+}
+
+impl<D, C> EmbeddedBackendConfig<D, C>
+where
+    D: DrawTarget<Color = C>,
+    C: PixelColor,
+{
+    /// Returns a builder that starts from the default configuration.
+    pub fn builder() -> EmbeddedBackendConfigBuilder<D, C> {
+        EmbeddedBackendConfigBuilder::default()
+    }
+
+    /// Returns a configuration for small monochrome displays, e.g. 128×64 SSD1306
+    /// OLEDs.
+    ///
+    /// Uses the 5×8 font, which fits a 25×8 grid on such a display, and the
+    /// [`ColorTheme::grayscale`] theme, so colors stay apart as `BinaryColor`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mousefood::embedded_graphics::{mock_display::MockDisplay, pixelcolor::BinaryColor};
+    /// use mousefood::prelude::*;
+    ///
+    /// let mut display = MockDisplay::<BinaryColor>::new();
+    /// let backend = EmbeddedBackend::new(&mut display, EmbeddedBackendConfig::compact());
+    /// ```
+    pub fn compact() -> Self {
+        Self {
+            font_regular: embedded_graphics::mono_font::ascii::FONT_5X8,
+            color_theme: ColorTheme::grayscale(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Builder for [`EmbeddedBackendConfig`].
+///
+/// Starts from [`EmbeddedBackendConfig::default`]. Fields without a builder method
+/// can still be set on the built config.
+///
+/// # Examples
+///
+/// ```rust
+/// use mousefood::embedded_graphics::mono_font::ascii::{FONT_6X13, FONT_6X13_BOLD};
+/// use mousefood::embedded_graphics::{mock_display::MockDisplay, pixelcolor::Rgb888};
+/// use mousefood::prelude::*;
+/// use mousefood::{CursorConfig, CursorStyle, TerminalAlignment};
+///
+/// let config = EmbeddedBackendConfig::builder()
+///     .with_font_regular(FONT_6X13)
+///     .with_bold(FONT_6X13_BOLD)
+///     .with_color_theme(ColorTheme::tokyo_night())
+///     .with_cursor(CursorConfig {
+///         style: CursorStyle::Underline,
+///         ..Default::default()
+///     })
+///     .with_alignment(TerminalAlignment::Center, TerminalAlignment::Center)
+///     .build();
+///
+/// let mut display = MockDisplay::<Rgb888>::new();
+/// let _backend = EmbeddedBackend::new(&mut display, config);
+/// ```
+pub struct EmbeddedBackendConfigBuilder<D, C>
+where
+    D: DrawTarget<Color = C>,
+    C: PixelColor,
+{
+    config: EmbeddedBackendConfig<D, C>,
+}
+
+impl<D, C> Default for EmbeddedBackendConfigBuilder<D, C>
+where
+    D: DrawTarget<Color = C>,
+    C: PixelColor,
+{
+    fn default() -> Self {
+        Self {
+            config: EmbeddedBackendConfig::default(),
         }
     }
 }
 
+impl<D, C> EmbeddedBackendConfigBuilder<D, C>
+where
+    D: DrawTarget<Color = C>,
+    C: PixelColor,
+{
+    /// Sets the regular font, which also determines the cell size.
+    pub fn with_font_regular(mut self, font: MonoFont<'static>) -> Self {
+        self.config.font_regular = font;
+        self
+    }
+
+    /// Sets the font for bold text.
+    pub fn with_bold(mut self, font: MonoFont<'static>) -> Self {
+        self.config.font_bold = Some(font);
+        self
+    }
+
+    /// Sets the color theme.
+    pub fn with_color_theme(mut self, color_theme: ColorTheme) -> Self {
+        self.config.color_theme = color_theme;
+        self
+    }
+
+    /// Sets the cursor appearance and blink behavior.
+    pub fn with_cursor(mut self, cursor: CursorConfig) -> Self {
+        self.config.cursor = cursor;
+        self
+    }
+
+    /// Sets how the view is aligned when the display size is not an exact multiple
+    /// of the cell size.
+    pub fn with_alignment(
+        mut self,
+        horizontal: TerminalAlignment,
+        vertical: TerminalAlignment,
+    ) -> Self {
+        self.config.horizontal_alignment = horizontal;
+        self.config.vertical_alignment = vertical;
+        self
+    }
+
+    /// Returns the built configuration.
+    pub fn build(self) -> EmbeddedBackendConfig<D, C> {
+        self.config
+    }
+}
+
 /// Embedded backend for Ratatui.
 ///
 /// # Examples
@@ -199,95 +1071,339 @@ where
     display: &'display mut D,
     display_type: PhantomData<D>,
 
-    flush_callback: Box<dyn FnMut(&mut D)>,
+    flush_callback: FlushFn<D>,
+    full_refresh: bool,
+    capabilities: DisplayCapabilities<D>,
+    dithering: Dithering,
+    conversion_quality: ConversionQuality,
+    gamma_lut: Option<&'static [u8; 256]>,
+    quantize: bool,
+    color_remap: Option<&'static [(style::Color, style::Color)]>,
+    max_cells_per_draw: Option<usize>,
+    #[cfg(feature = "glyph-cache")]
+    glyph_cache: crate::glyph_cache::GlyphCache<C>,
+    screensaver_timeout: Option<u32>,
+    screensaver_callback: ScreensaverFn<D>,
+    idle_frames: u32,
+    cursor_renderer: Option<CursorFn<D>>,
+    frame_budget: Option<Duration>,
+    frame_clock: Option<Box<dyn FnMut() -> Duration>>,
+    #[cfg(feature = "frame-limiter")]
+    frame_limiter: Option<crate::FrameLimiter>,
+    /// Whether the frame limiter held back a frame that wasn't written yet.
+    #[cfg(feature = "frame-limiter")]
+    frame_pending: bool,
+    watchdog_feed_callback: Option<Box<dyn FnMut()>>,
+    /// Clock reading at the start of the frame or the last watchdog feed.
+    frame_start: Duration,
+    screensaver_active: bool,
+    watermark: Option<(String, geometry::Point, C)>,
+    #[cfg(feature = "framebuffer")]
+    inverted: bool,
+    /// Cell the cursor was drawn over directly on the display.
+    #[cfg(feature = "framebuffer")]
+    cursor_area: Option<Rectangle>,
+    /// Area the watermark was drawn over directly on the display.
+    #[cfg(feature = "framebuffer")]
+    watermark_area: Option<Rectangle>,
 
     #[cfg(feature = "framebuffer")]
-    buffer: crate::framebuffer::HeapBuffer<C>,
+    buffer: crate::framebuffer::Framebuffer<'display, C>,
+    #[cfg(feature = "framebuffer")]
+    flush_strategy: FlushStrategy,
+    #[cfg(feature = "framebuffer")]
+    row_flush_callback: Option<RowFlushFn<D, C>>,
+    #[cfg(feature = "framebuffer")]
+    flush_retries: u8,
+    #[cfg(feature = "framebuffer")]
+    flush_retry_delay: Option<Box<dyn FnMut(u8)>>,
 
     font_regular: MonoFont<'static>,
     font_bold: Option<MonoFont<'static>>,
     font_italic: Option<MonoFont<'static>>,
+    font_bold_italic: Option<MonoFont<'static>>,
+    fallback_font: Option<MonoFont<'static>>,
 
     char_offset: geometry::Point,
 
     columns_rows: layout::Size,
     pixels: layout::Size,
+    vertical_alignment: TerminalAlignment,
+    horizontal_alignment: TerminalAlignment,
+    margin: (u16, u16, u16, u16),
+    line_height_percent: u16,
+    letter_spacing: u16,
+    line_spacing: u16,
     color_theme: ColorTheme,
     cursor: Cursor,
+    default_underline_color: Option<Rgb888>,
+    #[cfg(feature = "strikethrough-color")]
+    strikethrough_color: Option<Rgb888>,
+    manual_underline: bool,
+    underline_thickness: u32,
+    double_underline: bool,
+    cursor_before_callback: bool,
+    scale: u32,
+    rotation: Rotation,
+    native_block_elements: bool,
+    slow_display_blink_mode: SlowBlinkMode,
+    epaper_modifiers: bool,
+    clip_to_cell: bool,
+    clip_out_of_bounds: bool,
+    clip_shape: ClipShape,
     #[cfg(feature = "blink")]
     frame_count: u16,
     #[cfg(feature = "blink")]
     blink_config: BlinkConfig,
     #[cfg(feature = "blink")]
+    blink_paused: bool,
+    #[cfg(feature = "blink")]
     blink_cells: BTreeMap<(u16, u16), ratatui_core::buffer::Cell>,
+    /// Positions of cells drawn with the `REVERSED` modifier, for the inverse cursor.
+    #[cfg(feature = "framebuffer")]
+    reversed_cells: alloc::collections::BTreeSet<(u16, u16)>,
+    /// Pixels of the row below that the underline of each cell covers, for fonts
+    /// whose underline lies below the cell.
+    #[cfg(feature = "framebuffer")]
+    underline_overflow: UnderlineOverflow<C>,
+    #[cfg(feature = "framebuffer")]
+    background_layer: Option<crate::framebuffer::Framebuffer<'display, C>>,
+    /// Whether each cell shows the background layer, in row-major order.
+    #[cfg(feature = "framebuffer")]
+    background_cells: alloc::vec::Vec<bool>,
+    #[cfg(feature = "cell-cache")]
+    cells: alloc::vec::Vec<ratatui_core::buffer::Cell>,
+    #[cfg(feature = "alloc-stats")]
+    frame_alloc_start: usize,
+    #[cfg(feature = "alloc-stats")]
+    frame_allocations: usize,
+    #[cfg(feature = "stats")]
+    frame_stats: crate::stats::RenderStats,
+    #[cfg(feature = "stats")]
+    last_frame_stats: crate::stats::RenderStats,
 }
 
 impl<'display, D, C> EmbeddedBackend<'display, D, C>
 where
-    D: DrawTarget<Color = C> + Dimensions + 'static,
+    D: DrawTarget<Color = C> + Dimensions + 'display,
+    D::Error: core::fmt::Debug + Send + Sync + 'static,
     C: PixelColor + Into<Rgb888> + From<Rgb888> + for<'a> From<TermColor<'a>> + 'static,
 {
     fn init(
         display: &'display mut D,
         config: EmbeddedBackendConfig<D, C>,
+        #[cfg(feature = "framebuffer")] mut buffer: crate::framebuffer::Framebuffer<'display, C>,
     ) -> EmbeddedBackend<'display, D, C> {
         let EmbeddedBackendConfig {
             flush_callback,
             font_regular,
             font_bold,
             font_italic,
+            font_bold_italic,
+            fallback_font,
             vertical_alignment,
             horizontal_alignment,
+            margin,
+            line_height_percent,
+            letter_spacing,
+            line_spacing,
             color_theme,
             cursor,
+            default_underline_color,
+            #[cfg(feature = "strikethrough-color")]
+            strikethrough_color,
+            manual_underline,
+            underline_thickness,
+            double_underline,
+            cursor_before_callback,
+            scale,
+            rotation,
+            native_block_elements,
+            slow_display_blink_mode,
+            modifier_profile,
+            clip_to_cell,
+            clip_out_of_bounds,
+            clip_shape,
             #[cfg(feature = "blink")]
             blink,
+            #[cfg(feature = "framebuffer")]
+            flush_strategy,
+            #[cfg(feature = "framebuffer")]
+            row_flush_callback,
+            #[cfg(feature = "framebuffer")]
+            flush_retries,
+            #[cfg(feature = "framebuffer")]
+            flush_retry_delay,
+            #[cfg(feature = "framebuffer")]
+            double_buffer,
+            capabilities,
+            dithering,
+            conversion_quality,
+            gamma_lut,
+            quantize,
+            color_remap,
+            max_cells_per_draw,
+            #[cfg(feature = "glyph-cache")]
+            glyph_cache_size,
+            screensaver_timeout,
+            screensaver_callback,
+            cursor_renderer,
+            frame_budget,
+            frame_clock,
+            watchdog_feed_callback,
+            #[cfg(feature = "frame-limiter")]
+            frame_limiter,
         } = config;
-        let pixels = layout::Size {
-            width: display.bounding_box().size.width as u16,
-            height: display.bounding_box().size.height as u16,
+        #[cfg(all(feature = "blink", feature = "frame-limiter"))]
+        let blink = match &frame_limiter {
+            Some(limiter) => BlinkConfig {
+                fps: limiter.fps(),
+                ..blink
+            },
+            None => blink,
         };
+        #[cfg(feature = "framebuffer")]
+        if double_buffer {
+            buffer.set_front(None);
+        }
+        let scale = u32::from(scale.max(1));
+        let pixels = rotated_size(
+            layout::Size {
+                width: display.bounding_box().size.width as u16,
+                height: display.bounding_box().size.height as u16,
+            },
+            rotation,
+        );
+        let (columns_rows, char_offset) = grid_geometry(
+            display.bounding_box().top_left,
+            scaled_size(pixels, scale),
+            margin,
+            cell_size(
+                font_regular.character_size,
+                line_height_percent,
+                letter_spacing,
+                line_spacing,
+            ),
+            horizontal_alignment,
+            vertical_alignment,
+        );
 
-        let extra_x = pixels.width % font_regular.character_size.width as u16;
-        let extra_y = pixels.height % font_regular.character_size.height as u16;
-
-        let off_x = match horizontal_alignment {
-            TerminalAlignment::Start => 0,
-            TerminalAlignment::Center => extra_x / 2,
-            TerminalAlignment::End => extra_x,
-        } as i32;
-        let off_y = match vertical_alignment {
-            TerminalAlignment::Start => 0,
-            TerminalAlignment::Center => extra_y / 2,
-            TerminalAlignment::End => extra_y,
-        } as i32;
-
-        let char_offset = geometry::Point::new(off_x, off_y);
-
-        Self {
+        let backend = Self {
+            #[cfg(feature = "framebuffer")]
+            buffer,
+            #[cfg(feature = "framebuffer")]
+            flush_strategy,
             #[cfg(feature = "framebuffer")]
-            buffer: crate::framebuffer::HeapBuffer::new(display.bounding_box(), color_theme),
+            row_flush_callback,
+            #[cfg(feature = "framebuffer")]
+            flush_retries,
+            #[cfg(feature = "framebuffer")]
+            flush_retry_delay,
             display,
             display_type: PhantomData,
-            flush_callback: Box::new(flush_callback),
-            font_regular,
-            font_bold,
+            flush_callback,
+            capabilities,
+            dithering,
+            conversion_quality,
+            gamma_lut,
+            quantize,
+            color_remap,
+            max_cells_per_draw,
+            #[cfg(feature = "glyph-cache")]
+            glyph_cache: crate::glyph_cache::GlyphCache::new(glyph_cache_size),
+            screensaver_timeout,
+            screensaver_callback,
+            idle_frames: 0,
+            cursor_renderer,
+            frame_budget,
+            frame_clock,
+            watchdog_feed_callback,
+            #[cfg(feature = "frame-limiter")]
+            frame_limiter,
+            #[cfg(feature = "frame-limiter")]
+            frame_pending: false,
+            frame_start: Duration::ZERO,
+            screensaver_active: false,
+            full_refresh: true,
+            watermark: None,
+            #[cfg(feature = "framebuffer")]
+            inverted: false,
+            #[cfg(feature = "framebuffer")]
+            cursor_area: None,
+            #[cfg(feature = "framebuffer")]
+            watermark_area: None,
+            font_regular,
+            font_bold,
             font_italic,
+            font_bold_italic,
+            fallback_font,
             char_offset,
-            columns_rows: layout::Size {
-                height: pixels.height / font_regular.character_size.height as u16,
-                width: pixels.width / font_regular.character_size.width as u16,
-            },
+            columns_rows,
             pixels,
+            vertical_alignment,
+            horizontal_alignment,
+            margin,
+            line_height_percent,
+            letter_spacing,
+            line_spacing,
             color_theme,
-            cursor: Cursor::new(cursor),
+            cursor: Cursor {
+                scale,
+                ..Cursor::new(cursor)
+            },
+            default_underline_color,
+            #[cfg(feature = "strikethrough-color")]
+            strikethrough_color,
+            manual_underline,
+            underline_thickness,
+            double_underline,
+            cursor_before_callback,
+            scale,
+            rotation,
+            native_block_elements,
+            slow_display_blink_mode,
+            epaper_modifiers: match modifier_profile {
+                ModifierProfile::Auto => Self::is_epaper(),
+                ModifierProfile::Full => false,
+                ModifierProfile::EPaper => true,
+            },
+            clip_to_cell,
+            clip_out_of_bounds,
+            clip_shape,
             #[cfg(feature = "blink")]
             frame_count: 0,
             #[cfg(feature = "blink")]
             blink_config: blink,
             #[cfg(feature = "blink")]
+            blink_paused: false,
+            #[cfg(feature = "blink")]
             blink_cells: BTreeMap::new(),
-        }
+            #[cfg(feature = "framebuffer")]
+            reversed_cells: alloc::collections::BTreeSet::new(),
+            #[cfg(feature = "framebuffer")]
+            underline_overflow: UnderlineOverflow::default(),
+            #[cfg(feature = "framebuffer")]
+            background_layer: None,
+            #[cfg(feature = "framebuffer")]
+            background_cells: alloc::vec::Vec::new(),
+            #[cfg(feature = "cell-cache")]
+            cells: alloc::vec![ratatui_core::buffer::Cell::EMPTY; columns_rows.area() as usize],
+            #[cfg(feature = "alloc-stats")]
+            frame_alloc_start: crate::alloc_stats::allocations(),
+            #[cfg(feature = "alloc-stats")]
+            frame_allocations: 0,
+            #[cfg(feature = "stats")]
+            frame_stats: crate::stats::RenderStats::default(),
+            #[cfg(feature = "stats")]
+            last_frame_stats: crate::stats::RenderStats::default(),
+        };
+        #[cfg(feature = "framebuffer")]
+        let backend = {
+            let mut backend = backend;
+            backend.reset_underline_overflow();
+            backend
+        };
+        backend
     }
 
     /// Creates a new `EmbeddedBackend` using default fonts.
@@ -295,7 +1411,97 @@ where
         display: &'display mut D,
         config: EmbeddedBackendConfig<D, C>,
     ) -> EmbeddedBackend<'display, D, C> {
-        Self::init(display, config)
+        #[cfg(feature = "framebuffer")]
+        let buffer = crate::framebuffer::Framebuffer::new(
+            scaled_bounds(display.bounding_box(), config.scale, config.rotation),
+            config.color_theme,
+        );
+        Self::init(
+            display,
+            config,
+            #[cfg(feature = "framebuffer")]
+            buffer,
+        )
+    }
+
+    /// Creates a new `EmbeddedBackend` with the default configuration.
+    ///
+    /// Shorthand for `EmbeddedBackend::new(display, EmbeddedBackendConfig::default())`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mousefood::EmbeddedBackend;
+    /// use mousefood::embedded_graphics::{mock_display::MockDisplay, pixelcolor::Rgb888};
+    /// use ratatui::Terminal;
+    ///
+    /// let mut display = MockDisplay::<Rgb888>::new();
+    /// let backend = EmbeddedBackend::from_display(&mut display);
+    /// let _terminal = Terminal::new(backend).unwrap();
+    /// ```
+    pub fn from_display(display: &'display mut D) -> EmbeddedBackend<'display, D, C> {
+        Self::new(display, EmbeddedBackendConfig::default())
+    }
+
+    /// Creates a new `EmbeddedBackend` whose framebuffer uses `buffer` instead of
+    /// allocating on the heap.
+    ///
+    /// `buffer`, e.g. a [`StaticBuffer`](crate::StaticBuffer), must hold at least
+    /// `width × height` pixels of the display, otherwise this returns
+    /// [`Error::BufferSize`](crate::error::Error::BufferSize). Returns
+    /// [`Error::Unsupported`](crate::error::Error::Unsupported) if
+    /// [`double_buffer`](EmbeddedBackendConfig::double_buffer) is set, since the
+    /// front buffer would be allocated on the heap; use
+    /// [`with_double_buffer`](Self::with_double_buffer) instead.
+    #[cfg(feature = "framebuffer")]
+    pub fn with_buffer(
+        display: &'display mut D,
+        buffer: &'display mut [C],
+        config: EmbeddedBackendConfig<D, C>,
+    ) -> Result<EmbeddedBackend<'display, D, C>> {
+        if config.double_buffer {
+            return Err(crate::error::Error::Unsupported(
+                "allocating the front buffer of caller-provided storage",
+            ));
+        }
+        let bounding_box = scaled_bounds(display.bounding_box(), config.scale, config.rotation);
+        let actual = buffer.len();
+        let buffer =
+            crate::framebuffer::Framebuffer::from_slice(bounding_box, buffer, config.color_theme)
+                .ok_or(crate::error::Error::BufferSize {
+                expected: (bounding_box.size.width * bounding_box.size.height) as usize,
+                actual,
+            })?;
+        Ok(Self::init(display, config, buffer))
+    }
+
+    /// Creates a new [double-buffered](EmbeddedBackendConfig::double_buffer)
+    /// `EmbeddedBackend` that uses `back` and `front` as its framebuffers, without
+    /// allocating on the heap.
+    ///
+    /// Both must hold at least `width × height` pixels of the display, otherwise this
+    /// returns [`Error::BufferSize`](crate::error::Error::BufferSize).
+    #[cfg(feature = "framebuffer")]
+    pub fn with_double_buffer(
+        display: &'display mut D,
+        back: &'display mut [C],
+        front: &'display mut [C],
+        config: EmbeddedBackendConfig<D, C>,
+    ) -> Result<EmbeddedBackend<'display, D, C>> {
+        let config = EmbeddedBackendConfig {
+            double_buffer: false,
+            ..config
+        };
+        let mut backend = Self::with_buffer(display, back, config)?;
+        let actual = front.len();
+        if !backend.buffer.set_front(Some(front)) {
+            let size = backend.buffer.bounding_box().size;
+            return Err(crate::error::Error::BufferSize {
+                expected: (size.width * size.height) as usize,
+                actual,
+            });
+        }
+        Ok(backend)
     }
 
     /// Borrow the display
@@ -307,11 +1513,724 @@ where
     pub fn display_mut(&mut self) -> &mut D {
         self.display
     }
+
+    /// Draws all cells of `buffer` and flushes them to the display.
+    ///
+    /// Renders a hand-built [`Buffer`](ratatui_core::buffer::Buffer) without a
+    /// Ratatui `Terminal`, e.g. for a splash screen. Cells are placed at their
+    /// position in the buffer's area; cells marked with
+    /// [`CellDiffOption::Skip`](ratatui_core::buffer::CellDiffOption::Skip) are left
+    /// alone.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mousefood::embedded_graphics::{mock_display::MockDisplay, pixelcolor::Rgb888};
+    /// use mousefood::prelude::*;
+    /// use ratatui::buffer::Buffer;
+    /// use ratatui::layout::Rect;
+    ///
+    /// let mut display = MockDisplay::<Rgb888>::new();
+    /// let mut backend = EmbeddedBackend::new(&mut display, EmbeddedBackendConfig::default());
+    ///
+    /// let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+    /// buffer.set_string(0, 0, "Booting…", ratatui::style::Style::default());
+    /// backend.render_buffer(&buffer)?;
+    /// # Ok::<(), mousefood::error::Error>(())
+    /// ```
+    pub fn render_buffer(&mut self, buffer: &ratatui_core::buffer::Buffer) -> Result<()> {
+        use ratatui_core::buffer::CellDiffOption;
+
+        let cells = buffer
+            .content
+            .iter()
+            .enumerate()
+            .filter(|(_, cell)| cell.diff_option != CellDiffOption::Skip)
+            .map(|(index, cell)| {
+                let (x, y) = buffer.pos_of(index);
+                (x, y, cell)
+            });
+        self.draw(cells)?;
+        self.flush()
+    }
+
+    /// Changes the usable display area to `new_pixels`.
+    ///
+    /// Recomputes the terminal grid and, with the `framebuffer` feature, reallocates
+    /// the framebuffer. Ratatui picks up the new [`Backend::size`] on its next draw
+    /// and re-lays-out the whole UI. The area keeps the display's top-left corner.
+    ///
+    /// Returns [`Error::DisplaySize`](crate::error::Error::DisplaySize) if
+    /// `new_pixels` is larger than the display. A buffer passed to
+    /// [`with_buffer`](Self::with_buffer) isn't reallocated; if it is too small for
+    /// the new size, this returns
+    /// [`Error::BufferSize`](crate::error::Error::BufferSize).
+    pub fn resize(&mut self, new_pixels: layout::Size) -> Result<()> {
+        let display = self.display.bounding_box().size;
+        if u32::from(new_pixels.width) > display.width
+            || u32::from(new_pixels.height) > display.height
+        {
+            return Err(crate::error::Error::DisplaySize {
+                width: new_pixels.width.into(),
+                height: new_pixels.height.into(),
+                max_width: display.width,
+                max_height: display.height,
+            });
+        }
+        let new_pixels = rotated_size(new_pixels, self.rotation);
+        let logical = scaled_size(new_pixels, self.scale);
+        #[cfg(feature = "framebuffer")]
+        {
+            let bounding_box = Rectangle::new(
+                self.display.bounding_box().top_left,
+                geometry::Size::new(logical.width.into(), logical.height.into()),
+            );
+            if !self.buffer.reshape(bounding_box, self.color_theme) {
+                return Err(crate::error::Error::BufferSize {
+                    expected: logical.area() as usize,
+                    actual: self.buffer.capacity(),
+                });
+            }
+            if let Some(layer) = &mut self.background_layer
+                && !layer.reshape(bounding_box, self.color_theme)
+            {
+                return Err(crate::error::Error::BufferSize {
+                    expected: logical.area() as usize,
+                    actual: layer.capacity(),
+                });
+            }
+        }
+        let (columns_rows, char_offset) = grid_geometry(
+            self.display.bounding_box().top_left,
+            logical,
+            self.margin,
+            self.cell_size(),
+            self.horizontal_alignment,
+            self.vertical_alignment,
+        );
+        self.pixels = new_pixels;
+        self.columns_rows = columns_rows;
+        self.char_offset = char_offset;
+
+        #[cfg(feature = "blink")]
+        self.blink_cells.clear();
+        #[cfg(feature = "framebuffer")]
+        {
+            self.reversed_cells.clear();
+            self.reset_underline_overflow();
+            if self.background_layer.is_some() {
+                self.background_cells = alloc::vec![false; columns_rows.area() as usize];
+            }
+        }
+        #[cfg(feature = "cell-cache")]
+        self.cells.resize(
+            columns_rows.area() as usize,
+            ratatui_core::buffer::Cell::EMPTY,
+        );
+        self.clear()
+    }
+
+    /// Clears the screen to the theme background and flushes it right away.
+    ///
+    /// Call it before the first frame to show a clean screen while the application
+    /// starts, instead of whatever the display showed before.
+    pub fn present_cleared(&mut self) -> Result<()> {
+        self.clear()?;
+        self.flush()
+    }
+
+    /// Replaces the color theme.
+    ///
+    /// Cells are drawn with the new theme from now on and the next `clear` uses its
+    /// background. Since ratatui only redraws changed cells, call
+    /// [`Terminal::clear`](ratatui_core::terminal::Terminal::clear) to repaint the
+    /// whole screen right away.
+    pub fn set_color_theme(&mut self, theme: ColorTheme) {
+        self.color_theme = theme;
+    }
+
+    /// Returns the heap memory used by the framebuffer, in bytes.
+    ///
+    /// This is `width × height × size_of::<C>()` of the display, twice if
+    /// [`double_buffer`](EmbeddedBackendConfig::double_buffer) is set, and includes
+    /// caller-provided storage. Useful for sizing the heap.
+    #[cfg(feature = "framebuffer")]
+    pub fn framebuffer_bytes(&self) -> usize {
+        self.buffer.size_bytes()
+    }
+
+    /// Returns the framebuffer contents as a 24-bit BMP image.
+    ///
+    /// This is what the display shows after the next flush, without the cursor.
+    #[cfg(feature = "framebuffer")]
+    pub fn screenshot(&self) -> alloc::vec::Vec<u8> {
+        self.buffer.to_bmp()
+    }
+
+    /// Returns the background layer, creating it on first use.
+    ///
+    /// The background layer holds a static picture under the terminal content, such
+    /// as a pattern or a logo. Cleared cells show the background layer instead of the
+    /// theme background, until a cell is drawn over them. Changes to the layer show
+    /// up in cleared cells on the next flush.
+    ///
+    /// The layer starts out in the theme background color and takes as much memory
+    /// as the framebuffer.
+    #[cfg(feature = "framebuffer")]
+    pub fn background_layer_mut(&mut self) -> &mut crate::framebuffer::Framebuffer<'display, C> {
+        if self.background_layer.is_none() {
+            self.background_cells = alloc::vec![false; self.columns_rows.area() as usize];
+        }
+        self.background_layer.get_or_insert_with(|| {
+            crate::framebuffer::Framebuffer::new(self.buffer.bounding_box(), self.color_theme)
+        })
+    }
+
+    /// Scrolls the terminal content up by `lines` rows, clearing the rows exposed at
+    /// the bottom.
+    ///
+    /// Uses [`DisplayCapabilities::hw_scroll`] when available, drawing only the
+    /// exposed rows right away. Otherwise the framebuffer content is moved in
+    /// software and shows up on the next flush, or without the `framebuffer`
+    /// feature, the rows are redrawn from the cell cache. Without any of these, this
+    /// returns [`Error::Unsupported`](crate::error::Error::Unsupported).
+    pub fn scroll_up(&mut self, lines: u16) -> Result<()> {
+        let rows = self.columns_rows.height;
+        let lines = lines.min(rows);
+        let dy = lines as u32 * self.cell_size().height;
+
+        let hw_scrolled = match &mut self.capabilities.hw_scroll {
+            Some(scroll) if self.rotation == Rotation::Deg0 => {
+                scroll(self.display, dy * self.scale);
+                true
+            }
+            _ => false,
+        };
+        if !hw_scrolled {
+            #[cfg(any(feature = "framebuffer", feature = "cell-cache"))]
+            return self.scroll_region(0..rows, lines, true);
+            #[cfg(not(any(feature = "framebuffer", feature = "cell-cache")))]
+            return Err(crate::error::Error::Unsupported(
+                "scrolling without hw_scroll, framebuffer or cell-cache",
+            ));
+        }
+        #[cfg(any(feature = "blink", feature = "framebuffer", feature = "cell-cache"))]
+        self.move_rows(0..rows, lines, true)?;
+
+        // The display already shows the scrolled content, only the exposed rows need
+        // to be drawn.
+        let area = self.grid_area();
+        let exposed = Rectangle::new(
+            area.top_left + geometry::Point::new(0, (area.size.height - dy) as i32),
+            geometry::Size::new(area.size.width, dy),
+        );
+        #[cfg(feature = "framebuffer")]
+        {
+            self.buffer
+                .scroll_front_up(area, dy, self.background_color());
+            // The cursor and watermark were drawn on the display only, and moved up
+            // with its content.
+            let scrolled = geometry::Point::new(0, -(dy as i32));
+            for drawn in [self.cursor_area, self.watermark_area]
+                .into_iter()
+                .flatten()
+            {
+                self.buffer.mark_stale(drawn.translate(scrolled));
+            }
+            self.transfer_region(exposed)
+                .map_err(crate::error::Error::draw)?;
+            self.buffer.present(exposed);
+        }
+        #[cfg(not(feature = "framebuffer"))]
+        self.fill_rect(&exposed, self.background_color())?;
+        Ok(())
+    }
+
+    /// Inverts (`true`) or restores (`false`) all colors on the display.
+    ///
+    /// Uses [`DisplayCapabilities::hw_invert`] when available, otherwise the
+    /// framebuffer is inverted while it is transferred on flush. Without either,
+    /// this returns [`Error::Unsupported`](crate::error::Error::Unsupported).
+    pub fn set_inverted(&mut self, inverted: bool) -> Result<()> {
+        if let Some(invert) = &mut self.capabilities.hw_invert {
+            invert(self.display, inverted);
+            return Ok(());
+        }
+        #[cfg(not(feature = "framebuffer"))]
+        return Err(crate::error::Error::Unsupported(
+            "inverting without hw_invert or framebuffer",
+        ));
+        #[cfg(feature = "framebuffer")]
+        {
+            if self.inverted != inverted {
+                self.buffer.mark_stale(self.buffer.bounding_box());
+            }
+            self.inverted = inverted;
+            Ok(())
+        }
+    }
+
+    /// Sets a text overlay that is drawn on top of all content on every flush.
+    ///
+    /// `pos` is the top-left corner of the text in display pixels. The text is drawn
+    /// with the regular font and a transparent background.
+    pub fn set_watermark(&mut self, text: &str, pos: geometry::Point, color: C) {
+        self.clear_watermark();
+        self.watermark = Some((String::from(text), pos, color));
+        #[cfg(feature = "framebuffer")]
+        if let Some(area) = self.watermark_rect() {
+            self.buffer.mark_stale(area);
+        }
+    }
+
+    /// Removes the watermark set by [`set_watermark`](Self::set_watermark).
+    ///
+    /// The content below it is restored on the next flush. Without the
+    /// `framebuffer` feature, it's restored when the cells below are redrawn.
+    pub fn clear_watermark(&mut self) {
+        self.watermark = None;
+        #[cfg(feature = "framebuffer")]
+        if let Some(area) = self.watermark_area.take() {
+            self.buffer.mark_stale(area);
+        }
+    }
+
+    /// Freezes blinking text and cursor in their current phase.
+    ///
+    /// Visibility stays as it is until [`resume_blink`](Self::resume_blink) is called,
+    /// e.g. to take a screenshot without a blink cycle interfering.
+    #[cfg(feature = "blink")]
+    pub fn pause_blink(&mut self) {
+        self.blink_paused = true;
+    }
+
+    /// Resumes blinking from the phase it was paused in.
+    #[cfg(feature = "blink")]
+    pub fn resume_blink(&mut self) {
+        self.blink_paused = false;
+    }
+
+    /// Pauses or resumes blinking, keeping blinking text and cursor visible while paused.
+    ///
+    /// Unlike [`pause_blink`](Self::pause_blink), which freezes the current phase, this
+    /// shows everything until blinking is resumed, e.g. for a screensaver or a focus mode.
+    /// The [`BlinkConfig`] timings are left untouched.
+    #[cfg(feature = "blink")]
+    pub fn set_blink_paused(&mut self, paused: bool) {
+        self.blink_paused = paused;
+        if paused {
+            self.show_blinking();
+        }
+    }
+
+    /// Restarts the blink cycle, so blinking text and cursor start out visible.
+    #[cfg(feature = "blink")]
+    pub fn reset_blink(&mut self) {
+        self.frame_count = 0;
+        self.show_blinking();
+    }
+
+    /// Makes blinking text and cursor visible until the next blink tick.
+    #[cfg(feature = "blink")]
+    fn show_blinking(&mut self) {
+        for timing in [&mut self.blink_config.slow, &mut self.blink_config.fast]
+            .into_iter()
+            .chain(self.cursor.config.blink_timing.as_mut())
+        {
+            timing.hidden = false;
+            timing.intensity = 255;
+        }
+    }
+
+    /// Resets the inactivity timer of the screensaver, e.g. on a key press.
+    ///
+    /// Ends the screensaver if it's running.
+    pub fn note_activity(&mut self) {
+        self.idle_frames = 0;
+        if self.screensaver_active {
+            self.screensaver_active = false;
+            (self.screensaver_callback)(self.display, false);
+        }
+    }
+
+    /// Returns whether the screensaver is running.
+    pub fn screensaver_active(&self) -> bool {
+        self.screensaver_active
+    }
+
+    /// Seeds the framebuffer with pixels already shown on the display.
+    ///
+    /// When taking over a display that shows existing content (e.g. a bootloader
+    /// splash), the framebuffer otherwise starts blank and the first flush erases it.
+    /// Seeded pixels are preserved until ratatui draws over them.
+    ///
+    /// Pixels are consumed in row-major order, starting at the top-left corner.
+    #[cfg(feature = "framebuffer")]
+    pub fn prime_buffer<I>(&mut self, pixels: I)
+    where
+        I: IntoIterator<Item = C>,
+    {
+        self.buffer.fill_from(pixels);
+    }
+
+    /// Returns the text currently shown on the terminal grid.
+    ///
+    /// Each row of cells becomes one line, separated by `\n`. Trailing spaces are
+    /// kept so that columns line up. Useful for screen readers or remote monitoring.
+    #[cfg(feature = "cell-cache")]
+    pub fn grid_text(&self) -> alloc::string::String {
+        let width = self.columns_rows.width.max(1) as usize;
+        let mut text = alloc::string::String::with_capacity(self.cells.len() + width);
+        for (row, cells) in self.cells.chunks(width).enumerate() {
+            if row > 0 {
+                text.push('\n');
+            }
+            for cell in cells {
+                text.push_str(cell.symbol());
+            }
+        }
+        text
+    }
+
+    /// Re-renders the cells in `rect` using the theme's disabled colors.
+    ///
+    /// Gives disabled widgets a uniform greyed-out look: every cell keeps its symbol
+    /// and modifiers but is drawn with [`ColorTheme::disabled_fg`] on
+    /// [`ColorTheme::disabled_bg`]. Ratatui doesn't know about this, so call it after
+    /// each `Terminal::draw` and flush again.
+    ///
+    /// The cells are re-rendered from the cell cache, which keeps their original
+    /// colors, so this requires the `cell-cache` feature. Since ratatui doesn't redraw
+    /// cells it considers unchanged, call
+    /// [`Terminal::clear`](ratatui_core::terminal::Terminal::clear) to restore the
+    /// colors once the region is no longer disabled.
+    #[cfg(feature = "cell-cache")]
+    pub fn draw_disabled_region(&mut self, rect: layout::Rect) -> Result<()> {
+        use embedded_graphics::pixelcolor::RgbColor;
+
+        let area = rect.intersection(layout::Rect::from((
+            layout::Position::ORIGIN,
+            self.columns_rows,
+        )));
+        let fg = self.color_theme.disabled_fg;
+        let bg = self.color_theme.disabled_bg;
+        for position in area.positions() {
+            let idx = position.y as usize * self.columns_rows.width as usize + position.x as usize;
+            let mut cell = self.cells[idx].clone();
+            cell.fg = style::Color::Rgb(fg.r(), fg.g(), fg.b());
+            cell.bg = style::Color::Rgb(bg.r(), bg.g(), bg.b());
+            self.draw_cell(position.x, position.y, &cell)?;
+        }
+        Ok(())
+    }
+
+    /// Copies an image into the pixels covered by the grid region `rect`.
+    ///
+    /// `pixels` holds the image in row-major order, `img_width` pixels per row. The
+    /// image is placed at the top-left corner of the region and clipped to it.
+    /// Ratatui doesn't know about the image, so draw it after `Terminal::draw` and
+    /// flush again; it stays until the covered cells are redrawn.
+    pub fn draw_image(&mut self, rect: layout::Rect, pixels: &[C], img_width: u32) -> Result<()> {
+        if img_width == 0 {
+            return Ok(());
+        }
+        let img_height = (pixels.len() / img_width as usize) as u32;
+        let char_size = self.cell_size();
+        let top_left = self.cell_origin(rect.x, rect.y);
+        let size = geometry::Size::new(
+            img_width.min(rect.width as u32 * char_size.width),
+            img_height.min(rect.height as u32 * char_size.height),
+        );
+        let rows = pixels
+            .chunks_exact(img_width as usize)
+            .take(size.height as usize);
+        let colors = rows.flat_map(|row| row[..size.width as usize].iter().copied());
+        self.fill_contiguous(&Rectangle::new(top_left, size), colors)
+    }
+
+    /// Outlines the grid region `rect` with a `thickness` pixels wide ring, e.g. to
+    /// highlight the focused widget.
+    ///
+    /// The ring is drawn just inside the pixels covered by `rect`, over the cells at
+    /// its edges. Like [`draw_image`](Self::draw_image), draw it after
+    /// `Terminal::draw` and flush again.
+    pub fn draw_focus_ring(&mut self, rect: layout::Rect, color: C, thickness: u16) -> Result<()> {
+        let char_size = self.cell_size();
+        let area = Rectangle::new(
+            self.cell_origin(rect.x, rect.y),
+            geometry::Size::new(
+                rect.width as u32 * char_size.width,
+                rect.height as u32 * char_size.height,
+            ),
+        )
+        .intersection(&self.grid_area());
+        if area.is_zero_sized() {
+            return Ok(());
+        }
+        self.stroke_rect(&area, color, thickness.into())
+    }
+
+    /// Draws `text` at double height across the grid rows `row` and `row + 1`.
+    ///
+    /// Glyphs are stretched vertically with nearest-neighbor scaling, like the
+    /// double-height lines of DEC terminals. `style` sets the colors and the bold,
+    /// italic, underlined and crossed-out modifiers. Text beyond the last column is
+    /// cut off. Like [`draw_image`](Self::draw_image), draw it after `Terminal::draw`
+    /// and flush again.
+    pub fn draw_double_height(&mut self, row: u16, text: &str, style: style::Style) -> Result<()> {
+        let fg: C = TermColor::new(
+            style.fg.unwrap_or(style::Color::Reset),
+            TermColorType::Foreground,
+            &self.color_theme,
+        )
+        .into();
+        let bg: C = TermColor::new(
+            style.bg.unwrap_or(style::Color::Reset),
+            TermColorType::Background,
+            &self.color_theme,
+        )
+        .into();
+        let modifier = style.add_modifier - style.sub_modifier;
+        let font = self.font_for(modifier);
+        let mut style_builder = MonoTextStyleBuilder::new()
+            .font(&font)
+            .text_color(fg)
+            .background_color(bg);
+        if modifier.contains(style::Modifier::UNDERLINED) {
+            style_builder = style_builder.underline();
+        }
+        if modifier.contains(style::Modifier::CROSSED_OUT) {
+            style_builder = style_builder.strikethrough();
+        }
+
+        let columns = self.columns_rows.width as usize;
+        let text = text
+            .char_indices()
+            .nth(columns)
+            .map_or(text, |(i, _)| &text[..i]);
+        let origin = self.cell_origin(0, row);
+        let text = Text::with_baseline(
+            text,
+            origin,
+            style_builder.build(),
+            embedded_graphics::text::Baseline::Top,
+        );
+        #[cfg(feature = "framebuffer")]
+        let target = &mut self.buffer;
+        #[cfg(not(feature = "framebuffer"))]
+        let target = &mut Scaled::uniform(&mut *self.display, self.scale, self.rotation);
+        let mut scaled = Scaled {
+            target,
+            origin,
+            x: 1,
+            y: 2,
+            rotation: Rotation::Deg0,
+            size: geometry::Size::zero(),
+        };
+        draw_text(&text, &mut scaled, None)
+    }
+
+    /// Returns whether `ch` can be drawn with the configured fonts.
+    ///
+    /// A character renders if one of the configured fonts has a glyph for it,
+    /// or if it's a block element drawn natively. Other characters are drawn as the
+    /// font's replacement glyph.
+    pub fn can_render(&self, ch: char) -> bool {
+        ch == ' '
+            || [
+                Some(&self.font_regular),
+                self.font_bold.as_ref(),
+                self.font_italic.as_ref(),
+                self.font_bold_italic.as_ref(),
+                self.fallback_font.as_ref(),
+            ]
+            .into_iter()
+            .flatten()
+            .any(|font| font_covers(font, ch))
+            || self.native_block_elements
+                && block_element_rect(ch.encode_utf8(&mut [0; 4]), geometry::Size::new(1, 1))
+                    .is_some()
+    }
+
+    /// Draws `text` on the first row of the grid region `rect`, truncated to fit.
+    ///
+    /// Text wider than `rect` is cut off and ends with `…`, or `..` when the
+    /// regular font has no ellipsis glyph. Like [`draw_image`](Self::draw_image),
+    /// draw it after `Terminal::draw` and flush again.
+    pub fn draw_text_ellipsis(
+        &mut self,
+        rect: layout::Rect,
+        text: &str,
+        style: style::Style,
+    ) -> Result<()> {
+        let width = rect.width as usize;
+        let ellipsis = if font_covers(&self.font_regular, '…') {
+            "…"
+        } else {
+            ".."
+        };
+        let truncated = text.chars().count() > width;
+        let kept = if truncated {
+            width.saturating_sub(ellipsis.chars().count())
+        } else {
+            width
+        };
+        let chars = text
+            .chars()
+            .take(kept)
+            .chain(ellipsis.chars().filter(|_| truncated))
+            .take(width);
+
+        let mut cell = ratatui_core::buffer::Cell::EMPTY;
+        for (x, ch) in (rect.x..).zip(chars) {
+            cell.set_char(ch).set_style(style);
+            #[cfg(feature = "cell-cache")]
+            if !self.cache_cell(x, rect.y, &cell) {
+                continue;
+            }
+            self.draw_cell(x, rect.y, &cell)?;
+        }
+        Ok(())
+    }
+
+    /// Draws a table row: each column's text starts at its grid column on `row`.
+    ///
+    /// `columns` holds the starting column, text and style of each column. Text is
+    /// cut off where the next column starts, and all other cells of the row are
+    /// cleared. Like [`draw_image`](Self::draw_image), draw it after `Terminal::draw`
+    /// and flush again.
+    pub fn draw_columns(&mut self, row: u16, columns: &[(u16, &str, style::Style)]) -> Result<()> {
+        let width = self.columns_rows.width;
+        let mut columns = columns.to_vec();
+        columns.sort_by_key(|&(start, _, _)| start);
+
+        let mut cells = alloc::vec![ratatui_core::buffer::Cell::EMPTY; width as usize];
+        for (i, &(start, text, style)) in columns.iter().enumerate() {
+            let end = columns
+                .get(i + 1)
+                .map_or(width, |&(next, _, _)| next.min(width));
+            for (x, ch) in (start..end).zip(text.chars()) {
+                cells[x as usize].set_char(ch).set_style(style);
+            }
+        }
+        for (x, cell) in (0..).zip(&cells) {
+            #[cfg(feature = "cell-cache")]
+            if !self.cache_cell(x, row, cell) {
+                continue;
+            }
+            self.draw_cell(x, row, cell)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the top-left pixel of the cell under the cursor on the display.
+    ///
+    /// Includes the offset from the terminal alignment and the integer scale, so
+    /// `embedded_graphics` drawings can be placed relative to the cursor.
+    pub fn cursor_pixel_position(&self) -> geometry::Point {
+        let position = self.cursor.position;
+        self.cell_rect(position.x, position.y).top_left
+    }
+
+    /// Returns the pixels on the display covered by the cell at `x`, `y`.
+    ///
+    /// Includes the offset from the terminal alignment and the integer scale. Cells
+    /// outside the grid are extrapolated from the grid.
+    pub fn cell_rect(&self, x: u16, y: u16) -> Rectangle {
+        self.display_rect(&Rectangle::new(self.cell_origin(x, y), self.cell_size()))
+    }
+
+    /// Returns the pixels on the display covered by `area` of the terminal, after
+    /// scaling and rotating it.
+    fn display_rect(&self, area: &Rectangle) -> Rectangle {
+        let display = self.display.bounding_box();
+        crate::scale::scale_rect(
+            area,
+            display.top_left,
+            (self.scale, self.scale),
+            self.rotation,
+            display.size / self.scale,
+        )
+    }
+
+    /// Returns the cell covering the display pixel `point`, or `None` if the pixel
+    /// is outside the grid.
+    ///
+    /// Useful for hit testing touch input.
+    pub fn pixel_to_cell(&self, point: geometry::Point) -> Option<(u16, u16)> {
+        let scale = self.scale as i32;
+        let display = self.display.bounding_box();
+        let offset = point - display.top_left;
+        let offset = geometry::Point::new(offset.x.div_euclid(scale), offset.y.div_euclid(scale));
+        let point = display.top_left
+            + self
+                .rotation
+                .unrotate_point(offset, display.size / self.scale);
+        if !self.grid_area().contains(point) {
+            return None;
+        }
+        let cell_size = self.cell_size();
+        let offset = point - self.char_offset;
+        Some((
+            (offset.x / cell_size.width as i32) as u16,
+            (offset.y / cell_size.height as i32) as u16,
+        ))
+    }
+
+    /// Returns the number of heap allocations performed during the last frame.
+    ///
+    /// A frame spans from the start of [`Backend::draw`] to the end of
+    /// [`Backend::flush`]. Requires [`CountingAllocator`] to be installed as the
+    /// global allocator, otherwise this always returns `0`.
+    ///
+    /// [`CountingAllocator`]: crate::alloc_stats::CountingAllocator
+    #[cfg(feature = "alloc-stats")]
+    pub fn frame_allocations(&self) -> usize {
+        self.frame_allocations
+    }
+
+    /// Returns the rendering work performed during the last frame.
+    ///
+    /// A frame spans from the start of [`Backend::draw`] to the end of
+    /// [`Backend::flush`].
+    #[cfg(feature = "stats")]
+    pub fn last_frame_stats(&self) -> crate::stats::RenderStats {
+        self.last_frame_stats
+    }
+
+    /// Returns how many glyphs were copied from the glyph cache instead of being
+    /// drawn with the font.
+    ///
+    /// See [`EmbeddedBackendConfig::glyph_cache_size`].
+    #[cfg(feature = "glyph-cache")]
+    pub fn glyph_cache_hits(&self) -> u32 {
+        self.glyph_cache.hits()
+    }
+
+    /// Writes the frame the [frame limiter](EmbeddedBackendConfig::frame_limiter)
+    /// held back, without waiting for the next frame to be due.
+    ///
+    /// Call this before going idle, e.g. when an event-driven loop waits for input,
+    /// so the display shows the last frame. Does nothing if no frame is pending.
+    #[cfg(feature = "frame-limiter")]
+    pub fn flush_pending(&mut self) -> Result<()> {
+        if self.frame_pending {
+            self.write_frame()?;
+        }
+        Ok(())
+    }
+
+    /// Returns whether the frame limiter held back a frame that wasn't written yet.
+    #[cfg(feature = "frame-limiter")]
+    pub fn frame_pending(&self) -> bool {
+        self.frame_pending
+    }
 }
 
 impl<D, C> Backend for EmbeddedBackend<'_, D, C>
 where
-    D: DrawTarget<Color = C> + 'static,
+    D: DrawTarget<Color = C>,
+    D::Error: core::fmt::Debug + Send + Sync + 'static,
     C: PixelColor + Into<Rgb888> + From<Rgb888> + for<'a> From<TermColor<'a>> + 'static,
 {
     type Error = crate::error::Error;
@@ -320,20 +2239,121 @@ where
     where
         I: Iterator<Item = (u16, u16, &'a ratatui_core::buffer::Cell)>,
     {
+        #[cfg(feature = "alloc-stats")]
+        {
+            self.frame_alloc_start = crate::alloc_stats::allocations();
+        }
+        #[cfg(feature = "stats")]
+        {
+            self.frame_stats = crate::stats::RenderStats::default();
+        }
+
         #[cfg(feature = "blink")]
         {
-            self.frame_count = self.frame_count.wrapping_add(1);
-            let blink_toggled = self.blink_config.tick(self.frame_count);
+            let blink_toggled = if self.blink_paused {
+                self.blink_config.take_toggled()
+            } else {
+                self.frame_count = self.frame_count.wrapping_add(1);
+                self.blink_config.tick(self.frame_count)
+            };
             if blink_toggled {
                 self.redraw_blink_cells()?;
             }
         }
 
-        for (x, y, cell) in content {
+        self.start_frame_budget();
+
+        // Horizontal run of blank cells with the same background, filled at once.
+        let mut run: Option<(u16, u16, u16, C)> = None;
+        let mut row = None;
+        // Cell covered by the right half of the previous, double-width cell.
+        #[cfg(feature = "wide-chars")]
+        let mut continuation = None;
+        for (count, (x, y, cell)) in content.enumerate() {
+            if row.replace(y) != Some(y) {
+                self.feed_watchdog();
+            }
+            if let Some(limit) = self.max_cells_per_draw
+                && count >= limit
+            {
+                if let Some((x, y, len, color)) = run {
+                    self.fill_cells(x, y, len, color)?;
+                }
+                return Err(crate::error::Error::CellLimit(limit));
+            }
+            if !self.cell_visible(x, y) {
+                #[cfg(feature = "stats")]
+                {
+                    self.frame_stats.cells_skipped += 1;
+                }
+                continue;
+            }
+            #[cfg(feature = "wide-chars")]
+            {
+                if continuation.take() == Some((x, y)) {
+                    #[cfg(feature = "stats")]
+                    {
+                        self.frame_stats.cells_skipped += 1;
+                    }
+                    continue;
+                }
+                if unicode_width::UnicodeWidthStr::width(cell.symbol()) == 2 {
+                    continuation = Some((x + 1, y));
+                }
+            }
             #[cfg(feature = "blink")]
             self.track_blink_cell(x, y, cell);
+            #[cfg(feature = "framebuffer")]
+            self.track_reversed_cell(x, y, cell);
+            #[cfg(all(feature = "cell-cache", not(feature = "framebuffer")))]
+            let uncovers_below = self.removes_overflowing_underline(x, y, cell);
+            // Skip cells that are already on screen.
+            #[cfg(feature = "cell-cache")]
+            if !self.cache_cell(x, y, cell) {
+                #[cfg(feature = "stats")]
+                {
+                    self.frame_stats.cells_skipped += 1;
+                }
+                continue;
+            }
+            #[cfg(feature = "stats")]
+            {
+                self.frame_stats.cells_drawn += 1;
+            }
+            #[cfg(feature = "framebuffer")]
+            if let Some(shown) = self
+                .background_cells
+                .get_mut(y as usize * self.columns_rows.width as usize + x as usize)
+            {
+                *shown = false;
+            }
+            #[cfg(feature = "framebuffer")]
+            self.restore_underline_overflow(x, y, cell);
 
-            self.draw_cell(x, y, cell)?;
+            let blank = self.blank_background(cell);
+            run = match (run, blank) {
+                (Some((rx, ry, len, color)), Some(bg))
+                    if ry == y && rx + len == x && color == bg =>
+                {
+                    Some((rx, ry, len + 1, color))
+                }
+                (previous, blank) => {
+                    if let Some((rx, ry, len, color)) = previous {
+                        self.fill_cells(rx, ry, len, color)?;
+                    }
+                    if blank.is_none() {
+                        self.draw_cell(x, y, cell)?;
+                    }
+                    blank.map(|bg| (x, y, 1, bg))
+                }
+            };
+            #[cfg(all(feature = "cell-cache", not(feature = "framebuffer")))]
+            if uncovers_below {
+                self.redraw_cached_cell(x, y + 1)?;
+            }
+        }
+        if let Some((x, y, len, color)) = run {
+            self.fill_cells(x, y, len, color)?;
         }
 
         Ok(())
@@ -360,6 +2380,11 @@ where
 
     #[cfg(feature = "framebuffer")]
     fn clear(&mut self) -> Result<()> {
+        self.full_refresh = true;
+        #[cfg(feature = "cell-cache")]
+        self.cells.fill(ratatui_core::buffer::Cell::EMPTY);
+        self.reversed_cells.clear();
+        self.underline_overflow.clear();
         self.buffer
             .clear(
                 TermColor::new(
@@ -369,12 +2394,17 @@ where
                 )
                 .into(),
             )
-            .map_err(|_| crate::error::Error::DrawError)
+            .map_err(crate::error::Error::draw)?;
+        self.background_cells.fill(true);
+        self.composite_background(self.grid_area())
     }
 
     #[cfg(not(feature = "framebuffer"))]
     fn clear(&mut self) -> Result<()> {
-        self.display
+        self.full_refresh = true;
+        #[cfg(feature = "cell-cache")]
+        self.cells.fill(ratatui_core::buffer::Cell::EMPTY);
+        Scaled::uniform(&mut *self.display, self.scale, self.rotation)
             .clear(
                 TermColor::new(
                     style::Color::Reset,
@@ -383,18 +2413,34 @@ where
                 )
                 .into(),
             )
-            .map_err(|_| crate::error::Error::DrawError)
+            .map_err(crate::error::Error::draw)
     }
 
     fn clear_region(&mut self, clear_type: ClearType) -> Result<()> {
         match clear_type {
             ClearType::All => self.clear(),
-            ClearType::AfterCursor
-            | ClearType::BeforeCursor
-            | ClearType::CurrentLine
-            | ClearType::UntilNewLine => Err(crate::error::Error::ClearTypeUnsupported(
-                alloc::format!("{:?}", clear_type),
-            )),
+            ClearType::AfterCursor => {
+                let layout::Position { x, y } = self.cursor.position;
+                self.clear_cells(x, y, self.columns_rows.width)?;
+                for row in y.saturating_add(1)..self.columns_rows.height {
+                    self.clear_cells(0, row, self.columns_rows.width)?;
+                }
+                Ok(())
+            }
+            ClearType::BeforeCursor => {
+                let layout::Position { x, y } = self.cursor.position;
+                for row in 0..y.min(self.columns_rows.height) {
+                    self.clear_cells(0, row, self.columns_rows.width)?;
+                }
+                self.clear_cells(0, y, x.saturating_add(1))
+            }
+            ClearType::CurrentLine => {
+                self.clear_cells(0, self.cursor.position.y, self.columns_rows.width)
+            }
+            ClearType::UntilNewLine => {
+                let layout::Position { x, y } = self.cursor.position;
+                self.clear_cells(x, y, self.columns_rows.width)
+            }
         }
     }
 
@@ -402,6 +2448,16 @@ where
         Ok(self.columns_rows)
     }
 
+    #[cfg(feature = "scrolling-regions")]
+    fn scroll_region_up(&mut self, region: core::ops::Range<u16>, line_count: u16) -> Result<()> {
+        self.scroll_region(region, line_count, true)
+    }
+
+    #[cfg(feature = "scrolling-regions")]
+    fn scroll_region_down(&mut self, region: core::ops::Range<u16>, line_count: u16) -> Result<()> {
+        self.scroll_region(region, line_count, false)
+    }
+
     fn window_size(&mut self) -> Result<ratatui_core::backend::WindowSize> {
         Ok(ratatui_core::backend::WindowSize {
             columns_rows: self.columns_rows,
@@ -410,90 +2466,357 @@ where
     }
 
     fn flush(&mut self) -> Result<()> {
-        #[cfg(feature = "framebuffer")]
-        self.display
-            .fill_contiguous(&self.display.bounding_box(), &self.buffer)
-            .map_err(|_| crate::error::Error::DrawError)?;
-
-        if self.cursor.visible {
-            #[cfg(feature = "blink")]
-            let hidden = self.cursor.config.blink && self.blink_config.slow.is_hidden();
-            #[cfg(not(feature = "blink"))]
-            let hidden = false;
+        #[cfg(feature = "frame-limiter")]
+        let due = self
+            .frame_limiter
+            .as_mut()
+            .is_none_or(|limiter| limiter.begin_frame());
+        #[cfg(not(feature = "frame-limiter"))]
+        let due = true;
+        if due {
+            self.write_frame()?;
+        }
+        #[cfg(feature = "frame-limiter")]
+        {
+            self.frame_pending = !due;
+        }
 
-            if !hidden {
-                let char_w = self.font_regular.character_size.width as i32;
-                let char_h = self.font_regular.character_size.height as i32;
-                self.cursor.draw(
-                    self.display,
-                    #[cfg(feature = "framebuffer")]
-                    &self.buffer,
-                    self.char_offset,
-                    char_w,
-                    char_h,
-                )?;
+        if let Some(timeout) = self.screensaver_timeout {
+            self.idle_frames = self.idle_frames.saturating_add(1);
+            if !self.screensaver_active && self.idle_frames >= timeout {
+                self.screensaver_active = true;
+                (self.screensaver_callback)(self.display, true);
             }
         }
 
-        (self.flush_callback)(self.display);
+        #[cfg(feature = "alloc-stats")]
+        {
+            let now = crate::alloc_stats::allocations();
+            self.frame_allocations = now - self.frame_alloc_start;
+            self.frame_alloc_start = now;
+        }
+        #[cfg(feature = "stats")]
+        {
+            self.last_frame_stats = core::mem::take(&mut self.frame_stats);
+        }
         Ok(())
     }
 }
 
 impl<D, C> EmbeddedBackend<'_, D, C>
 where
-    D: DrawTarget<Color = C> + 'static,
+    D: DrawTarget<Color = C>,
+    D::Error: core::fmt::Debug + Send + Sync + 'static,
     C: PixelColor + Into<Rgb888> + From<Rgb888> + for<'a> From<TermColor<'a>> + 'static,
 {
+    /// Writes the framebuffer, the watermark and the cursor to the display and calls
+    /// the flush callback.
+    fn write_frame(&mut self) -> Result<()> {
+        #[cfg(feature = "frame-limiter")]
+        {
+            self.frame_pending = false;
+        }
+
+        #[cfg(feature = "framebuffer")]
+        if let Some(area) = self
+            .background_layer
+            .as_mut()
+            .and_then(|layer| layer.take_dirty())
+        {
+            self.composite_background(area)?;
+        }
+
+        // The display already shows an unchanged frame, so neither the framebuffer,
+        // the watermark nor the cursor are written again.
+        #[cfg(feature = "framebuffer")]
+        let changed = self.frame_changed();
+        #[cfg(not(feature = "framebuffer"))]
+        let changed = true;
+
+        #[cfg(feature = "framebuffer")]
+        let mut dirty_rect = None;
+        #[cfg(not(feature = "framebuffer"))]
+        let dirty_rect = self.display.bounding_box();
+
+        #[cfg(feature = "framebuffer")]
+        if changed
+            && let Some(dirty) = self.take_dirty()
+            && let Some(changed) = self.buffer.changed_rows(dirty)
+        {
+            let area = if self.capabilities.windowing {
+                changed
+            } else {
+                self.buffer.bounding_box()
+            };
+            let chunk_height = if self.frame_budget.is_some() && self.capabilities.windowing {
+                self.cell_size().height
+            } else {
+                area.size.height
+            };
+            for chunk in row_chunks(area, chunk_height) {
+                self.feed_watchdog();
+                let mut retry = 0;
+                while let Err(error) = self.transfer_region(chunk) {
+                    if retry == self.flush_retries {
+                        self.buffer.mark_dirty(area);
+                        return Err(crate::error::Error::draw(error));
+                    }
+                    retry += 1;
+                    if let Some(delay) = &mut self.flush_retry_delay {
+                        delay(retry);
+                    }
+                }
+            }
+            self.buffer.swap(area);
+            dirty_rect = Some(
+                Scaled::uniform(&mut *self.display, self.scale, self.rotation).scale_rect(&area),
+            );
+        }
+
+        if changed {
+            self.draw_watermark()?;
+            #[cfg(feature = "framebuffer")]
+            if let Some(watermark) = self.watermark_area {
+                let watermark = Scaled::uniform(&mut *self.display, self.scale, self.rotation)
+                    .scale_rect(&watermark);
+                dirty_rect = crate::framebuffer::union(dirty_rect, watermark);
+            }
+            if self.cursor_before_callback {
+                self.draw_cursor()?;
+                #[cfg(feature = "framebuffer")]
+                if let Some(cursor) = self.cursor_area {
+                    let cursor = Scaled::uniform(&mut *self.display, self.scale, self.rotation)
+                        .scale_rect(&cursor);
+                    dirty_rect = crate::framebuffer::union(dirty_rect, cursor);
+                }
+            }
+            #[cfg(feature = "framebuffer")]
+            let dirty_rect = dirty_rect.unwrap_or_else(Rectangle::zero);
+            let context = FlushContext {
+                full_refresh: core::mem::take(&mut self.full_refresh),
+                dirty_rect,
+            };
+            (self.flush_callback)(self.display, &context);
+            if !self.cursor_before_callback {
+                self.draw_cursor()?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Resolves a cell color to the display color and the RGB color it came from,
+    /// applying the color remap, quantization and the gamma lookup table.
+    fn cell_color(&self, color: style::Color, color_type: TermColorType) -> (C, Rgb888) {
+        let color = self
+            .color_remap
+            .and_then(|remap| remap.iter().find(|&&(from, _)| from == color))
+            .map_or(color, |&(_, to)| to);
+        let mut rgb = self.color_theme.resolve(color, color_type);
+        let mut color: C = TermColor::new(color, color_type, &self.color_theme).into();
+        if self.quantize {
+            rgb = self.color_theme.quantize(rgb);
+            color = TermColor::new(rgb_color(rgb), color_type, &self.color_theme).into();
+        }
+        if let Some(lut) = self.gamma_lut {
+            rgb = apply_lut(rgb, lut);
+            color = TermColor::new(rgb_color(rgb), color_type, &self.color_theme).into();
+        }
+        if self.conversion_quality == ConversionQuality::Truncate
+            && let Some(bits) = channel_bits::<C>()
+        {
+            rgb = truncate_channels(rgb, bits, None);
+            color = TermColor::new(rgb_color(rgb), color_type, &self.color_theme).into();
+        }
+        (color, rgb)
+    }
+
+    /// Returns the background color of `cell` if it's a blank cell that can be drawn
+    /// by filling it, without rendering a glyph.
+    fn blank_background(&self, cell: &ratatui_core::buffer::Cell) -> Option<C> {
+        (cell.symbol() == " " && cell.modifier.is_empty() && self.dither_bits().is_none())
+            .then(|| self.cell_color(cell.bg, TermColorType::Background).0)
+    }
+
+    /// Returns whether cells are dithered, and to how many bits per channel.
+    ///
+    /// `Some(None)` dithers to black and white.
+    fn dither_bits(&self) -> Option<Option<[u8; 3]>> {
+        match (self.dithering, self.conversion_quality) {
+            (Dithering::Bayer4x4, _) => Some(None),
+            (Dithering::None, ConversionQuality::Dither) => channel_bits::<C>().map(Some),
+            (Dithering::None, _) => None,
+        }
+    }
+
+    /// Fills `len` blank cells starting at `x`, `y` with `color`.
+    fn fill_cells(&mut self, x: u16, y: u16, len: u16, color: C) -> Result<()> {
+        let char_size = self.cell_size();
+        let area = Rectangle::new(
+            self.cell_origin(x, y),
+            geometry::Size::new(len as u32 * char_size.width, char_size.height),
+        );
+        self.fill_rect(&area, color)
+    }
+
+    /// Resets `len` cells starting at `x`, `y` to blank cells with the reset
+    /// background color.
+    fn clear_cells(&mut self, x: u16, y: u16, len: u16) -> Result<()> {
+        let len = len.min(self.columns_rows.width.saturating_sub(x));
+        if y >= self.columns_rows.height || len == 0 {
+            return Ok(());
+        }
+        #[cfg(feature = "cell-cache")]
+        {
+            let start = y as usize * self.columns_rows.width as usize + x as usize;
+            self.cells[start..start + len as usize].fill(ratatui_core::buffer::Cell::EMPTY);
+        }
+        #[cfg(feature = "blink")]
+        self.blink_cells
+            .retain(|&(cx, cy), _| cy != y || cx < x || cx >= x + len);
+        #[cfg(feature = "framebuffer")]
+        {
+            self.reversed_cells
+                .retain(|&(cx, cy)| cy != y || cx < x || cx >= x + len);
+            for cx in x..x + len {
+                self.restore_underline_overflow(cx, y, &ratatui_core::buffer::Cell::EMPTY);
+            }
+        }
+        self.fill_cells(x, y, len, self.background_color())?;
+        #[cfg(feature = "framebuffer")]
+        if self.background_layer.is_some() {
+            let start = y as usize * self.columns_rows.width as usize + x as usize;
+            self.background_cells[start..start + len as usize].fill(true);
+            let char_size = self.cell_size();
+            self.composite_background(Rectangle::new(
+                self.cell_origin(x, y),
+                geometry::Size::new(len as u32 * char_size.width, char_size.height),
+            ))?;
+        }
+        Ok(())
+    }
+
+    /// Returns whether the center of the cell at `x`, `y` lies within the
+    /// [`ClipShape`] of the display.
+    fn cell_visible(&self, x: u16, y: u16) -> bool {
+        let char_size = self.cell_size();
+        let display = self.display.bounding_box();
+        let display = Rectangle::new(
+            display.top_left,
+            self.rotation.rotate_size(display.size) / self.scale,
+        );
+        if self.clip_out_of_bounds {
+            let cell = Rectangle::new(self.cell_origin(x, y), char_size);
+            if display.intersection(&cell) != cell {
+                return false;
+            }
+        }
+        match self.clip_shape {
+            ClipShape::Rectangle => true,
+            ClipShape::Circle => {
+                // Coordinates are doubled to keep the centers on whole numbers.
+                let center = display.top_left * 2 + display.size;
+                let cell = self.cell_origin(x, y) * 2 + char_size;
+                let (dx, dy) = ((cell.x - center.x) as i64, (cell.y - center.y) as i64);
+                let diameter = display.size.width.min(display.size.height) as i64;
+                dx * dx + dy * dy <= diameter * diameter
+            }
+        }
+    }
+
     fn draw_cell(&mut self, x: u16, y: u16, cell: &ratatui_core::buffer::Cell) -> Result<()> {
-        let position = geometry::Point::new(
-            x as i32 * self.font_regular.character_size.width as i32,
-            y as i32 * self.font_regular.character_size.height as i32,
-        );
-        let mut fg_color: C =
-            TermColor::new(cell.fg, TermColorType::Foreground, &self.color_theme).into();
-        let mut bg_color: C =
-            TermColor::new(cell.bg, TermColorType::Background, &self.color_theme).into();
+        if !self.cell_visible(x, y) {
+            return Ok(());
+        }
+        #[cfg(feature = "framebuffer")]
+        self.track_reversed_cell(x, y, cell);
+        #[cfg(feature = "framebuffer")]
+        {
+            // The framebuffer only reports pixels that changed, so a cell redrawn
+            // as it was isn't transferred again. Changed cells are sent whole.
+            let dirty = self.buffer.take_dirty();
+            let result = self.render_cell(x, y, cell);
+            if let Some(changed) = self.buffer.take_dirty() {
+                self.buffer
+                    .mark_dirty(Rectangle::new(self.cell_origin(x, y), self.cell_size()));
+                self.buffer.mark_dirty(changed);
+            }
+            if let Some(dirty) = dirty {
+                self.buffer.mark_dirty(dirty);
+            }
+            result
+        }
+        #[cfg(not(feature = "framebuffer"))]
+        self.render_cell(x, y, cell)
+    }
+
+    /// Draws `cell` at column `x` and row `y`, which must be visible.
+    fn render_cell(&mut self, x: u16, y: u16, cell: &ratatui_core::buffer::Cell) -> Result<()> {
+        let cell_size = self.cell_size();
+        let top_left = self.cell_origin(x, y);
+        // The RGB colors are kept in step with the display colors for dithering.
+        let (mut fg_color, mut fg_rgb) = self.cell_color(cell.fg, TermColorType::Foreground);
+        let (mut bg_color, mut bg_rgb) = self.cell_color(cell.bg, TermColorType::Background);
+        let mut font = self.font_for(cell.modifier);
+        if let Some(fallback) = self.fallback_font
+            && !cell.symbol().chars().all(|ch| font_covers(&font, ch))
+        {
+            font = fallback;
+        }
         let mut style_builder = MonoTextStyleBuilder::new()
-            .font(&self.font_regular)
+            .font(&font)
             .text_color(fg_color)
             .background_color(bg_color);
 
         for modifier in cell.modifier.iter() {
             style_builder = match modifier {
-                style::Modifier::BOLD => match &self.font_bold {
-                    None => style_builder,
-                    Some(font) => style_builder.font(font),
-                },
+                // E-paper can't show dimmed or blinking text.
+                style::Modifier::DIM
+                | style::Modifier::SLOW_BLINK
+                | style::Modifier::RAPID_BLINK
+                    if self.epaper_modifiers =>
+                {
+                    style_builder
+                }
                 style::Modifier::DIM => {
-                    fg_color = dim_color(fg_color);
+                    // A dimmed color would snap to either on or off, so monochrome
+                    // displays keep the text as is (unless dithering it).
+                    if !Self::is_monochrome() {
+                        fg_color = dim_color(fg_color);
+                    }
+                    fg_rgb = dim_color(fg_rgb);
                     style_builder
                 }
-                style::Modifier::ITALIC => match &self.font_italic {
-                    None => style_builder,
-                    Some(font) => style_builder.font(font),
-                },
                 style::Modifier::UNDERLINED => style_builder.underline(),
                 #[cfg(feature = "blink")]
                 style::Modifier::SLOW_BLINK => {
-                    if self.blink_config.slow.is_hidden() {
-                        fg_color = bg_color;
+                    if self.slow_display_blink_mode == SlowBlinkMode::None {
+                        (fg_color, fg_rgb) = self.blink_foreground(
+                            &self.blink_config.slow,
+                            (fg_color, fg_rgb),
+                            (bg_color, bg_rgb),
+                        );
                     }
                     style_builder
                 }
                 #[cfg(feature = "blink")]
                 style::Modifier::RAPID_BLINK => {
-                    if self.blink_config.fast.is_hidden() {
-                        fg_color = bg_color;
+                    if self.slow_display_blink_mode == SlowBlinkMode::None {
+                        (fg_color, fg_rgb) = self.blink_foreground(
+                            &self.blink_config.fast,
+                            (fg_color, fg_rgb),
+                            (bg_color, bg_rgb),
+                        );
                     }
                     style_builder
                 }
                 style::Modifier::REVERSED => {
                     core::mem::swap(&mut fg_color, &mut bg_color);
+                    core::mem::swap(&mut fg_rgb, &mut bg_rgb);
                     style_builder
                 }
                 style::Modifier::HIDDEN => {
                     fg_color = bg_color;
+                    fg_rgb = bg_rgb;
                     style_builder
                 }
                 style::Modifier::CROSSED_OUT => style_builder.strikethrough(),
@@ -501,149 +2824,4349 @@ where
             }
         }
 
+        let blinking = cell
+            .modifier
+            .intersects(style::Modifier::SLOW_BLINK | style::Modifier::RAPID_BLINK);
+        if blinking && self.slow_display_blink_mode == SlowBlinkMode::Invert {
+            core::mem::swap(&mut fg_color, &mut bg_color);
+            core::mem::swap(&mut fg_rgb, &mut bg_rgb);
+        }
+
+        if self.native_block_elements
+            && let Some(block) = block_element_rect(cell.symbol(), cell_size)
+        {
+            let cell_rect = Rectangle::new(top_left, cell_size);
+            self.fill_rect(&cell_rect, bg_color)?;
+            return self.fill_rect(&block.translate(top_left), fg_color);
+        }
+
+        use embedded_graphics::pixelcolor::RgbColor;
+        let dither_bits = self.dither_bits();
+        let dither_keys = dither_bits.map(|_| (C::from(Rgb888::WHITE), C::from(Rgb888::BLACK)));
+        let (text_color, background_color) = dither_keys.unwrap_or((fg_color, bg_color));
         style_builder = style_builder
-            .text_color(fg_color)
-            .background_color(bg_color);
+            .text_color(text_color)
+            .background_color(background_color);
+
+        let mut underline = cell
+            .modifier
+            .contains(style::Modifier::UNDERLINED)
+            .then_some(fg_color);
+        if let Some(color) = self.default_underline_color
+            && cell.modifier.contains(style::Modifier::UNDERLINED)
+        {
+            style_builder = style_builder.underline_with_color(color.into());
+            underline = Some(color.into());
+        }
 
         #[cfg(feature = "underline-color")]
         if cell.underline_color != style::Color::Reset {
-            style_builder = style_builder.underline_with_color(
-                TermColor::new(
-                    cell.underline_color,
-                    TermColorType::Foreground,
-                    &self.color_theme,
-                )
-                .into(),
-            );
+            let color = TermColor::new(
+                cell.underline_color,
+                TermColorType::Foreground,
+                &self.color_theme,
+            )
+            .into();
+            style_builder = style_builder.underline_with_color(color);
+            underline = Some(color);
+        }
+
+        #[cfg(feature = "strikethrough-color")]
+        if let Some(color) = self.strikethrough_color
+            && cell.modifier.contains(style::Modifier::CROSSED_OUT)
+        {
+            style_builder = style_builder.strikethrough_with_color(color.into());
+        }
+
+        let manual_underline = self.draws_manual_underline();
+        if manual_underline {
+            style_builder = style_builder.reset_underline();
         }
 
-        Text::with_baseline(
+        let cell_rect = Rectangle::new(top_left, cell_size);
+        // Glyphs are centered vertically in rows taller than the font, and the
+        // glyph background doesn't cover the letter and line spacing.
+        let glyph_y = self.glyph_offset() as i32;
+        if glyph_y > 0 || self.letter_spacing > 0 || self.line_spacing > 0 {
+            self.fill_rect(&cell_rect, bg_color)?;
+        }
+        let text = Text::with_baseline(
             cell.symbol(),
-            position + self.char_offset,
+            cell_rect.top_left + geometry::Point::new(0, glyph_y),
             style_builder.build(),
             embedded_graphics::text::Baseline::Top,
-        )
-        .draw(
-            #[cfg(feature = "framebuffer")]
-            &mut self.buffer,
-            #[cfg(not(feature = "framebuffer"))]
-            self.display,
-        )
-        .map_err(|_| crate::error::Error::DrawError)?;
+        );
+        #[cfg(feature = "framebuffer")]
+        let target = &mut self.buffer;
+        #[cfg(not(feature = "framebuffer"))]
+        let target = &mut Scaled::uniform(&mut *self.display, self.scale, self.rotation);
+        // Glyphs extend below the cell if the font is taller than the line height,
+        // e.g. their descenders, or if its underline is placed below the cell. Glyphs
+        // that fit their cell aren't clipped.
+        let glyph_area = text.bounding_box();
+        let glyph_area = Rectangle::new(
+            glyph_area.top_left,
+            geometry::Size::new(
+                glyph_area.size.width,
+                glyph_area.size.height.max(font.character_size.height),
+            ),
+        );
+        let clip = (self.clip_to_cell && cell_rect.intersection(&glyph_area) != glyph_area)
+            .then_some(&cell_rect);
+        // Without a bold font, e-paper bold text is drawn again one pixel to the right.
+        let double_strike = (self.epaper_modifiers
+            && self.font_bold.is_none()
+            && cell.modifier.contains(style::Modifier::BOLD))
+        .then(|| {
+            let mut text = text;
+            text.position.x += 1;
+            text.character_style.background_color = None;
+            text
+        });
+        #[cfg(feature = "stats")]
+        {
+            self.frame_stats.glyph_draws += 1 + u32::from(double_strike.is_some());
+        }
+        match dither_keys {
+            None => {
+                #[cfg(feature = "glyph-cache")]
+                {
+                    use crate::glyph_cache::{GlyphRecorder, GlyphStyle, Lookup};
+                    let style = GlyphStyle {
+                        fg: fg_color,
+                        bg: bg_color,
+                        underline,
+                        modifier: cell.modifier,
+                    };
+                    let area = text.bounding_box();
+                    match self.glyph_cache.get(cell.symbol(), &style) {
+                        Lookup::Hit(pixels) => target
+                            .fill_contiguous(&area, pixels.iter().copied())
+                            .map_err(crate::error::Error::draw)?,
+                        Lookup::Uncacheable => draw_text(&text, target, clip)?,
+                        Lookup::Miss => {
+                            let mut recorder = GlyphRecorder::new(target, area);
+                            draw_text(&text, &mut recorder, clip)?;
+                            self.glyph_cache
+                                .insert(cell.symbol(), style, recorder.finish());
+                        }
+                    }
+                }
+                #[cfg(not(feature = "glyph-cache"))]
+                draw_text(&text, target, clip)?;
+                if let Some(text) = &double_strike {
+                    draw_text(text, target, Some(&cell_rect))?;
+                }
+            }
+            Some((on, off)) => {
+                let mut dithered = DitherTarget {
+                    target,
+                    channel_bits: dither_bits.flatten(),
+                    on,
+                    off,
+                    foreground: fg_rgb,
+                    background: bg_rgb,
+                };
+                draw_text(&text, &mut dithered, clip)?;
+                if let Some(text) = &double_strike {
+                    draw_text(text, &mut dithered, Some(&cell_rect))?;
+                }
+            }
+        }
+
+        if let Some(color) = underline
+            && manual_underline
+        {
+            let bottom = cell_rect.top_left + geometry::Point::new(0, cell_size.height as i32);
+            if self.double_underline {
+                for y in [1, 3] {
+                    let line = Rectangle::new(
+                        bottom - geometry::Point::new(0, y),
+                        geometry::Size::new(cell_size.width, 1),
+                    );
+                    self.fill_rect(&line.intersection(&cell_rect), color)?;
+                }
+            } else {
+                let thickness = self.underline_thickness.min(cell_size.height);
+                let line = Rectangle::new(
+                    bottom - geometry::Point::new(0, thickness as i32),
+                    geometry::Size::new(cell_size.width, thickness),
+                );
+                self.fill_rect(&line, color)?;
+            }
+        } else if let Some(color) = underline
+            && font.character_size.width < cell_size.width
+        {
+            // A bold or italic font narrower than the cell underlines only part of
+            // it, leaving gaps between underlined cells.
+            let rest = Rectangle::new(
+                cell_rect.top_left
+                    + geometry::Point::new(
+                        font.character_size.width as i32,
+                        glyph_y + font.underline.offset as i32,
+                    ),
+                geometry::Size::new(
+                    cell_size.width - font.character_size.width,
+                    font.underline.height,
+                ),
+            );
+            let rest = match clip {
+                Some(clip) => rest.intersection(clip),
+                None => rest,
+            };
+            self.fill_rect(&rest, color)?;
+        }
+
+        if blinking && self.slow_display_blink_mode == SlowBlinkMode::Border {
+            self.stroke_rect(&cell_rect, fg_color, 1)?;
+        }
 
         Ok(())
     }
 
-    #[cfg(feature = "blink")]
-    fn track_blink_cell(&mut self, x: u16, y: u16, cell: &ratatui_core::buffer::Cell) {
-        if cell.modifier.contains(style::Modifier::SLOW_BLINK)
-            || cell.modifier.contains(style::Modifier::RAPID_BLINK)
-        {
-            self.blink_cells.insert((x, y), cell.clone());
-        } else {
-            self.blink_cells.remove(&(x, y));
+    /// Returns the area of the framebuffer that needs to be transferred, including
+    /// the cell the cursor was last drawn over.
+    #[cfg(feature = "framebuffer")]
+    fn take_dirty(&mut self) -> Option<Rectangle> {
+        if let Some(area) = self.cursor_area.take() {
+            self.buffer.mark_stale(area);
         }
+        if let Some(area) = self.watermark_area.take() {
+            self.buffer.mark_stale(area);
+        }
+        self.buffer.take_dirty()
     }
 
-    #[cfg(feature = "blink")]
-    fn redraw_blink_cells(&mut self) -> Result<()> {
-        if self.blink_cells.is_empty() {
+    /// Moves the rows in `region` up (or down) by `lines`, clearing the rows exposed at
+    /// the bottom (or top).
+    ///
+    /// The framebuffer content is moved in place and shows up on the next flush.
+    /// Without the framebuffer, the region is redrawn from the cell cache.
+    #[cfg(any(feature = "framebuffer", feature = "cell-cache"))]
+    fn scroll_region(&mut self, region: core::ops::Range<u16>, lines: u16, up: bool) -> Result<()> {
+        let region = self.move_rows(region, lines, up)?;
+        if region.is_empty() {
             return Ok(());
         }
-        let cells = core::mem::take(&mut self.blink_cells);
-        for (&(x, y), cell) in &cells {
-            self.draw_cell(x, y, cell)?;
+        #[cfg(feature = "framebuffer")]
+        {
+            let area = self.rows_area(region);
+            self.buffer.mark_dirty(area);
+        }
+        #[cfg(all(not(feature = "framebuffer"), feature = "cell-cache"))]
+        for y in region {
+            for x in 0..self.columns_rows.width {
+                self.redraw_cached_cell(x, y)?;
+            }
         }
-        self.blink_cells = cells;
         Ok(())
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use {
-        super::*,
-        embedded_graphics::{
-            mock_display::MockDisplay,
-            mono_font::{MonoTextStyle, ascii::FONT_4X6},
-            pixelcolor::{Rgb888, RgbColor},
-            prelude::*,
-            text::{Alignment, LineHeight, Text, TextStyleBuilder},
-        },
-        ratatui::Terminal,
-        rstest::{fixture, rstest},
-    };
-
-    #[fixture]
-    fn display0() -> MockDisplay<Rgb888> {
-        let mut d = MockDisplay::new();
-        d.set_allow_overdraw(true);
-        d
-    }
-
-    #[fixture]
-    fn display1() -> MockDisplay<Rgb888> {
-        display0()
-    }
 
-    #[rstest]
-    fn renders_direct_as_expected(
-        mut display0: MockDisplay<Rgb888>,
-        mut display1: MockDisplay<Rgb888>,
-    ) {
-        let config = || EmbeddedBackendConfig {
-            font_regular: FONT_4X6,
-            font_bold: None,
-            vertical_alignment: TerminalAlignment::Start,
-            horizontal_alignment: TerminalAlignment::Start,
-            ..Default::default()
+    /// Moves the cells and framebuffer content of the rows in `region` up (or down)
+    /// by `lines`, clearing the rows exposed at the bottom (or top).
+    ///
+    /// Returns the rows that moved, or an empty range if none did. The moved content
+    /// isn't marked dirty, since a display that scrolled in hardware already shows
+    /// it.
+    #[cfg(any(feature = "blink", feature = "framebuffer", feature = "cell-cache"))]
+    fn move_rows(
+        &mut self,
+        region: core::ops::Range<u16>,
+        lines: u16,
+        up: bool,
+    ) -> Result<core::ops::Range<u16>> {
+        let rows = self.columns_rows.height;
+        let region = region.start.min(rows)..region.end.min(rows);
+        let lines = lines.min(region.len() as u16);
+        if lines == 0 {
+            return Ok(region.start..region.start);
+        }
+        // New row of a cell in row `y`, or `None` if it's scrolled out of the region.
+        #[cfg(any(feature = "blink", feature = "framebuffer"))]
+        let moved = |y: u16| match region.contains(&y) {
+            false => Some(y),
+            true if up => y.checked_sub(lines).filter(|y| *y >= region.start),
+            true => Some(y + lines).filter(|y| *y < region.end),
         };
 
-        //render "T" via ratatui and then " est" directly to the display retrieved from the backend
+        #[cfg(any(feature = "framebuffer", feature = "cell-cache"))]
+        let width = self.columns_rows.width as usize;
+        #[cfg(any(feature = "framebuffer", feature = "cell-cache"))]
+        let (cells, shift) = (
+            region.start as usize * width..region.end as usize * width,
+            lines as usize * width,
+        );
+        #[cfg(feature = "cell-cache")]
+        shift_cells(
+            &mut self.cells[cells.clone()],
+            shift,
+            up,
+            &ratatui_core::buffer::Cell::EMPTY,
+        );
+        #[cfg(feature = "blink")]
         {
-            let backend = EmbeddedBackend::new(&mut display0, config());
-            let mut terminal = Terminal::new(backend).expect("to create terminal");
-            terminal
-                .draw(|frame| {
-                    use ratatui::text::Line;
-                    let content = Line::from("T").left_aligned();
-                    frame.render_widget(content, frame.area());
-                })
-                .expect("to draw");
-
-            let display = terminal.backend_mut().display_mut();
+            self.blink_cells = core::mem::take(&mut self.blink_cells)
+                .into_iter()
+                .filter_map(|((x, y), cell)| Some(((x, moved(y)?), cell)))
+                .collect();
+        }
 
-            let text = {
-                let text_style = TextStyleBuilder::new()
-                    .alignment(Alignment::Left)
-                    .line_height(LineHeight::Percent(100))
-                    .baseline(embedded_graphics::text::Baseline::Top)
-                    .build();
+        #[cfg(feature = "framebuffer")]
+        {
+            self.reversed_cells = core::mem::take(&mut self.reversed_cells)
+                .into_iter()
+                .filter_map(|(x, y)| Some((x, moved(y)?)))
+                .collect();
+            let cell_height = self.cell_size().height;
+            self.underline_overflow
+                .scroll(region.clone(), lines, up, cell_height);
 
-                Text::with_text_style(
-                    " est",
-                    Point::new(0, 0),
-                    MonoTextStyle::new(&FONT_4X6, Rgb888::WHITE),
-                    text_style,
+            let area = self.rows_area(region.clone());
+            let dy = lines as u32 * cell_height;
+            let background = self.background_color();
+            let exposed = if up {
+                Rectangle::new(
+                    area.top_left + geometry::Point::new(0, (area.size.height - dy) as i32),
+                    geometry::Size::new(area.size.width, dy),
                 )
+            } else {
+                Rectangle::new(area.top_left, geometry::Size::new(area.size.width, dy))
             };
-            text.draw(display).unwrap();
+            if up {
+                self.buffer.scroll_up(area, dy, background);
+            } else {
+                self.buffer.scroll_down(area, dy, background);
+            }
+            if self.background_layer.is_some() {
+                shift_cells(&mut self.background_cells[cells], shift, up, &true);
+                self.composite_background(exposed)?;
+            }
         }
+        Ok(region)
+    }
 
-        //render "Test" via ratatui
-        {
-            let backend = EmbeddedBackend::new(&mut display1, config());
-            let mut terminal = Terminal::new(backend).expect("to create terminal");
+    /// Pixel area covered by the grid rows in `rows`.
+    #[cfg(feature = "framebuffer")]
+    fn rows_area(&self, rows: core::ops::Range<u16>) -> Rectangle {
+        Rectangle::new(
+            self.cell_origin(0, rows.start),
+            geometry::Size::new(
+                self.grid_area().size.width,
+                rows.len() as u32 * self.cell_size().height,
+            ),
+        )
+    }
 
-            terminal
-                .draw(|frame| {
-                    use ratatui::text::Line;
-                    let content = Line::from("Test").left_aligned();
-                    frame.render_widget(content, frame.area());
-                })
-                .expect("to draw");
+    /// Falls back to clearing the rows in `region` and requesting a full refresh,
+    /// since there's no record of the content to move.
+    ///
+    /// The cleared rows show up again once they are redrawn, e.g. after
+    /// `Terminal::clear`.
+    #[cfg(all(
+        feature = "scrolling-regions",
+        not(any(feature = "framebuffer", feature = "cell-cache"))
+    ))]
+    fn scroll_region(&mut self, region: core::ops::Range<u16>, lines: u16, _: bool) -> Result<()> {
+        if lines == 0 {
+            return Ok(());
         }
-
+        self.full_refresh = true;
+        for y in region {
+            self.clear_cells(0, y, self.columns_rows.width)?;
+        }
+        Ok(())
+    }
+
+    /// Whether the framebuffer or the cursor changed since the last flush, or the
+    /// cursor blinks.
+    #[cfg(feature = "framebuffer")]
+    fn frame_changed(&self) -> bool {
+        if self.buffer.is_dirty() {
+            return true;
+        }
+        #[cfg(feature = "blink")]
+        if self.cursor.visible && self.cursor.config.blink {
+            return true;
+        }
+        let (Some(last_column), Some(last_row)) = (
+            self.columns_rows.width.checked_sub(1),
+            self.columns_rows.height.checked_sub(1),
+        ) else {
+            return false;
+        };
+        let position = self.cursor.position;
+        let cursor_area = self.cursor.visible.then(|| {
+            Rectangle::new(
+                self.cell_origin(position.x.min(last_column), position.y.min(last_row)),
+                self.cell_size(),
+            )
+        });
+        cursor_area != self.cursor_area
+    }
+
+    /// Restarts the frame budget at the current time.
+    fn start_frame_budget(&mut self) {
+        if self.frame_budget.is_some()
+            && let Some(clock) = &mut self.frame_clock
+        {
+            self.frame_start = clock();
+        }
+    }
+
+    /// Feeds the watchdog if the frame budget has elapsed since the frame started or
+    /// the watchdog was last fed.
+    fn feed_watchdog(&mut self) {
+        let (Some(budget), Some(clock), Some(feed)) = (
+            self.frame_budget,
+            &mut self.frame_clock,
+            &mut self.watchdog_feed_callback,
+        ) else {
+            return;
+        };
+        let now = clock();
+        if now.saturating_sub(self.frame_start) >= budget {
+            feed();
+            self.frame_start = now;
+        }
+    }
+
+    /// Transfers the part of the framebuffer inside `area` to the display.
+    #[cfg(feature = "framebuffer")]
+    fn transfer_region(&mut self, area: Rectangle) -> core::result::Result<(), D::Error> {
+        use embedded_graphics::primitives::PointsIter;
+
+        let area = area.intersection(&self.buffer.bounding_box());
+        let inverted = self.inverted;
+        if let Some(write_row) = &mut self.row_flush_callback
+            && !inverted
+            && self.scale == 1
+            && self.rotation == Rotation::Deg0
+        {
+            for (row, pixels) in self.buffer.rows(area) {
+                write_row(self.display, row, pixels)?;
+            }
+            return Ok(());
+        }
+        let pixels = self
+            .buffer
+            .region(area)
+            .map(|color| if inverted { invert_color(color) } else { color });
+        let mut display = Scaled::uniform(&mut *self.display, self.scale, self.rotation);
+        match self.flush_strategy {
+            FlushStrategy::FillContiguous => display.fill_contiguous(&area, pixels),
+            FlushStrategy::DrawIter => display.draw_iter(
+                area.points()
+                    .zip(pixels)
+                    .map(|(point, color)| embedded_graphics::Pixel(point, color)),
+            ),
+        }?;
+        Ok(())
+    }
+
+    /// Whether the display only has on and off pixels.
+    ///
+    /// Color-based modifiers fall back to on/off friendly rendering on such displays.
+    fn is_monochrome() -> bool {
+        core::any::TypeId::of::<C>() == core::any::TypeId::of::<BinaryColor>()
+    }
+
+    /// Whether the display is an e-paper panel from one of the `epd-*` features.
+    fn is_epaper() -> bool {
+        let epaper: &[core::any::TypeId] = &[
+            #[cfg(feature = "epd-weact")]
+            core::any::TypeId::of::<weact_studio_epd::Color>(),
+            #[cfg(feature = "epd-weact")]
+            core::any::TypeId::of::<weact_studio_epd::TriColor>(),
+            #[cfg(feature = "epd-waveshare")]
+            core::any::TypeId::of::<epd_waveshare::color::Color>(),
+            #[cfg(feature = "epd-waveshare")]
+            core::any::TypeId::of::<epd_waveshare::color::TriColor>(),
+            #[cfg(feature = "epd-waveshare")]
+            core::any::TypeId::of::<epd_waveshare::color::OctColor>(),
+        ];
+        epaper.contains(&core::any::TypeId::of::<C>())
+    }
+
+    /// Whether underlines are drawn as lines at the bottom of the cell instead of by
+    /// the font.
+    fn draws_manual_underline(&self) -> bool {
+        self.manual_underline || self.double_underline || self.epaper_modifiers
+    }
+
+    /// Draws the watermark, if any, directly on the display over the current content.
+    ///
+    /// Like the cursor, the watermark isn't part of the framebuffer, so it doesn't
+    /// move with scrolled content and its area is restored on the next flush.
+    fn draw_watermark(&mut self) -> Result<()> {
+        let Some((text, position, color)) = &self.watermark else {
+            return Ok(());
+        };
+        let style = MonoTextStyleBuilder::new()
+            .font(&self.font_regular)
+            .text_color(*color)
+            .build();
+        let text = Text::with_baseline(
+            text,
+            *position,
+            style,
+            embedded_graphics::text::Baseline::Top,
+        );
+        #[cfg(feature = "framebuffer")]
+        {
+            self.watermark_area = Some(text.bounding_box());
+        }
+        draw_text(
+            &text,
+            &mut Scaled::uniform(&mut *self.display, self.scale, self.rotation),
+            None,
+        )
+    }
+
+    /// Returns the terminal pixels covered by the watermark, if any.
+    #[cfg(feature = "framebuffer")]
+    fn watermark_rect(&self) -> Option<Rectangle> {
+        let (text, position, color) = self.watermark.as_ref()?;
+        let style = embedded_graphics::mono_font::MonoTextStyle::new(&self.font_regular, *color);
+        Some(
+            Text::with_baseline(
+                text,
+                *position,
+                style,
+                embedded_graphics::text::Baseline::Top,
+            )
+            .bounding_box(),
+        )
+    }
+
+    /// Returns the foreground of blinking text in the current phase of `timing`, as
+    /// the display color and its RGB equivalent.
+    #[cfg(feature = "blink")]
+    fn blink_foreground(
+        &self,
+        timing: &BlinkTiming,
+        fg: (C, Rgb888),
+        bg: (C, Rgb888),
+    ) -> (C, Rgb888) {
+        match self.blink_config.style {
+            BlinkStyle::Fade if !Self::is_monochrome() => {
+                let amount = 255 - timing.intensity();
+                (
+                    blend_colors(fg.0, bg.0, amount),
+                    blend_colors(fg.1, bg.1, amount),
+                )
+            }
+            _ if !timing.is_hidden() => fg,
+            BlinkStyle::Dim if !Self::is_monochrome() => (dim_color(fg.0), dim_color(fg.1)),
+            _ => bg,
+        }
+    }
+
+    /// Returns the font for text with `modifier`.
+    fn font_for(&self, modifier: style::Modifier) -> MonoFont<'static> {
+        let bold = modifier.contains(style::Modifier::BOLD);
+        let italic = modifier.contains(style::Modifier::ITALIC);
+        match (bold, italic) {
+            (true, true) => self
+                .font_bold_italic
+                .as_ref()
+                .or(self.font_bold.as_ref())
+                .or(self.font_italic.as_ref()),
+            (true, false) => self.font_bold.as_ref(),
+            (false, true) => self.font_italic.as_ref(),
+            (false, false) => None,
+        }
+        .copied()
+        .unwrap_or(self.font_regular)
+    }
+
+    /// Size of a terminal cell in pixels.
+    fn cell_size(&self) -> geometry::Size {
+        cell_size(
+            self.font_regular.character_size,
+            self.line_height_percent,
+            self.letter_spacing,
+            self.line_spacing,
+        )
+    }
+
+    /// Top-left pixel of the cell at column `x` and row `y`, before scaling.
+    fn cell_origin(&self, x: u16, y: u16) -> geometry::Point {
+        let cell_size = self.cell_size();
+        self.char_offset
+            + geometry::Point::new(
+                x as i32 * cell_size.width as i32,
+                y as i32 * cell_size.height as i32,
+            )
+    }
+
+    /// Vertical offset of glyphs in cells taller than the font due to the line height.
+    fn glyph_offset(&self) -> u32 {
+        let font_height = self.font_regular.character_size.height;
+        let line_height = self.cell_size().height - u32::from(self.line_spacing);
+        line_height.saturating_sub(font_height) / 2
+    }
+
+    /// Color of cleared cells.
+    fn background_color(&self) -> C {
+        TermColor::new(
+            style::Color::Reset,
+            TermColorType::Background,
+            &self.color_theme,
+        )
+        .into()
+    }
+
+    /// Pixel area covered by the terminal grid.
+    fn grid_area(&self) -> Rectangle {
+        let char_size = self.cell_size();
+        Rectangle::new(
+            self.char_offset,
+            geometry::Size::new(
+                self.columns_rows.width as u32 * char_size.width,
+                self.columns_rows.height as u32 * char_size.height,
+            ),
+        )
+    }
+
+    /// Fills `area` with `color` on the framebuffer, or the display without it.
+    fn fill_rect(&mut self, area: &Rectangle, color: C) -> Result<()> {
+        #[cfg(feature = "stats")]
+        {
+            self.frame_stats.fill_solid_calls += 1;
+        }
+        #[cfg(feature = "framebuffer")]
+        let target = &mut self.buffer;
+        #[cfg(not(feature = "framebuffer"))]
+        let target = &mut Scaled::uniform(&mut *self.display, self.scale, self.rotation);
+        target
+            .fill_solid(area, color)
+            .map_err(crate::error::Error::draw)
+    }
+
+    /// Fills `area` with `colors` on the framebuffer, or the display without it.
+    fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<()>
+    where
+        I: IntoIterator<Item = C>,
+    {
+        #[cfg(feature = "framebuffer")]
+        let target = &mut self.buffer;
+        #[cfg(not(feature = "framebuffer"))]
+        let target = &mut Scaled::uniform(&mut *self.display, self.scale, self.rotation);
+        target
+            .fill_contiguous(area, colors)
+            .map_err(crate::error::Error::draw)
+    }
+
+    /// Draws a `thickness` pixels wide outline just inside `area`.
+    fn stroke_rect(&mut self, area: &Rectangle, color: C, thickness: u32) -> Result<()> {
+        let geometry::Size { width, height } = area.size;
+        let t = thickness.min(width).min(height);
+        let top_left = area.top_left;
+        let bottom = top_left + geometry::Point::new(0, (height - t) as i32);
+        let right = top_left + geometry::Point::new((width - t) as i32, 0);
+        self.fill_rect(
+            &Rectangle::new(top_left, geometry::Size::new(width, t)),
+            color,
+        )?;
+        self.fill_rect(
+            &Rectangle::new(bottom, geometry::Size::new(width, t)),
+            color,
+        )?;
+        self.fill_rect(
+            &Rectangle::new(top_left, geometry::Size::new(t, height)),
+            color,
+        )?;
+        self.fill_rect(
+            &Rectangle::new(right, geometry::Size::new(t, height)),
+            color,
+        )
+    }
+
+    fn draw_cursor(&mut self) -> Result<()> {
+        // Keep the cursor on the grid, e.g. after the last column of a tiny grid.
+        let (Some(last_column), Some(last_row)) = (
+            self.columns_rows.width.checked_sub(1),
+            self.columns_rows.height.checked_sub(1),
+        ) else {
+            return Ok(());
+        };
+        let position = layout::Position::new(
+            self.cursor.position.x.min(last_column),
+            self.cursor.position.y.min(last_row),
+        );
+
+        if self.cursor.visible {
+            #[cfg(feature = "blink")]
+            let hidden = self.cursor.config.blink
+                && match &mut self.cursor.config.blink_timing {
+                    Some(timing) => {
+                        if !self.blink_paused {
+                            timing.update(self.frame_count, self.blink_config.fps);
+                        }
+                        timing.is_hidden()
+                    }
+                    None => self.blink_config.slow.is_hidden(),
+                };
+            #[cfg(not(feature = "blink"))]
+            let hidden = false;
+
+            if !hidden {
+                let cell_size = self.cell_size();
+                let cell = Rectangle::new(self.cell_origin(position.x, position.y), cell_size);
+                #[cfg(feature = "framebuffer")]
+                {
+                    self.cursor_area = Some(cell);
+                }
+                #[cfg(feature = "framebuffer")]
+                let reversed = self.reversed_cells.contains(&(position.x, position.y));
+                // The cursor is drawn scaled but unrotated, so only the rotation is
+                // left to apply.
+                self.cursor.draw(
+                    &mut CursorTarget::new(&mut *self.display, self.rotation),
+                    #[cfg(feature = "framebuffer")]
+                    &self.buffer,
+                    #[cfg(feature = "framebuffer")]
+                    reversed,
+                    self.cursor_renderer.as_mut(),
+                    cell,
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Stores `cell` in the cell cache. Returns `false` if it was already cached,
+    /// i.e. the cell on screen is unchanged.
+    #[cfg(feature = "cell-cache")]
+    fn cache_cell(&mut self, x: u16, y: u16, cell: &ratatui_core::buffer::Cell) -> bool {
+        if x >= self.columns_rows.width || y >= self.columns_rows.height {
+            return true;
+        }
+        let idx = y as usize * self.columns_rows.width as usize + x as usize;
+        if self.cells[idx] == *cell {
+            return false;
+        }
+        self.cells[idx].clone_from(cell);
+        true
+    }
+
+    /// Returns whether drawing `cell` at `x`, `y` removes an underline that the
+    /// previous cell there drew into the row below, e.g. when it's cleared.
+    #[cfg(all(feature = "cell-cache", not(feature = "framebuffer")))]
+    fn removes_overflowing_underline(
+        &self,
+        x: u16,
+        y: u16,
+        cell: &ratatui_core::buffer::Cell,
+    ) -> bool {
+        let font = &self.font_regular;
+        if self.clip_to_cell
+            || self.draws_manual_underline()
+            || self.glyph_offset() + font.underline.offset < self.cell_size().height
+            || x >= self.columns_rows.width
+            || y + 1 >= self.columns_rows.height
+        {
+            return false;
+        }
+        let previous = &self.cells[y as usize * self.columns_rows.width as usize + x as usize];
+        previous.modifier.contains(style::Modifier::UNDERLINED)
+            && !cell.modifier.contains(style::Modifier::UNDERLINED)
+    }
+
+    /// Draws the cached cell at `x`, `y` again.
+    #[cfg(all(feature = "cell-cache", not(feature = "framebuffer")))]
+    fn redraw_cached_cell(&mut self, x: u16, y: u16) -> Result<()> {
+        let cell = self.cells[y as usize * self.columns_rows.width as usize + x as usize].clone();
+        self.draw_cell(x, y, &cell)
+    }
+
+    #[cfg(feature = "blink")]
+    fn track_blink_cell(&mut self, x: u16, y: u16, cell: &ratatui_core::buffer::Cell) {
+        if self.slow_display_blink_mode == SlowBlinkMode::None
+            && (cell.modifier.contains(style::Modifier::SLOW_BLINK)
+                || cell.modifier.contains(style::Modifier::RAPID_BLINK))
+        {
+            self.blink_cells.insert((x, y), cell.clone());
+        } else {
+            self.blink_cells.remove(&(x, y));
+        }
+    }
+
+    /// Copies the background layer into the cells inside `area` that show it.
+    #[cfg(feature = "framebuffer")]
+    fn composite_background(&mut self, area: Rectangle) -> Result<()> {
+        let grid = self.grid_area();
+        let Some(layer) = &self.background_layer else {
+            return Ok(());
+        };
+        let area = area.intersection(&grid);
+        if area.is_zero_sized() {
+            return Ok(());
+        }
+        let char_size = self.cell_size();
+        let (char_w, char_h) = (char_size.width as i32, char_size.height as i32);
+        let start = area.top_left - grid.top_left;
+        let end = start + area.size;
+        for y in start.y / char_h..(end.y + char_h - 1) / char_h {
+            for x in start.x / char_w..(end.x + char_w - 1) / char_w {
+                let index = y as usize * self.columns_rows.width as usize + x as usize;
+                if !self.background_cells[index] {
+                    continue;
+                }
+                let cell = Rectangle::new(
+                    grid.top_left + geometry::Point::new(x * char_w, y * char_h),
+                    char_size,
+                )
+                .intersection(&area);
+                self.buffer
+                    .fill_contiguous(&cell, layer.region(cell))
+                    .map_err(crate::error::Error::draw)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Restores the row below the cell at `x`, `y` from the underline of the
+    /// previous cell, and saves what the underline of `cell` will cover.
+    ///
+    /// The cell at `x`, `y + 1` paints over the underline of the cell above, which
+    /// then no longer needs restoring.
+    #[cfg(feature = "framebuffer")]
+    fn restore_underline_overflow(&mut self, x: u16, y: u16, cell: &ratatui_core::buffer::Cell) {
+        if let Some(above) = y.checked_sub(1) {
+            self.underline_overflow.remove(x, above);
+        }
+        if let Some((area, pixels)) = self.underline_overflow.take(x, y) {
+            let _ = self.buffer.fill_contiguous(&area, pixels.iter().copied());
+        }
+        let strip = self.underline_overflow.strip();
+        if strip.height == 0 || !cell.modifier.contains(style::Modifier::UNDERLINED) {
+            return;
+        }
+        let top_left = self.cell_origin(x, y + 1);
+        let area = Rectangle::new(top_left, strip).intersection(&self.buffer.bounding_box());
+        let pixels = embedded_graphics::primitives::PointsIter::points(&area)
+            .filter_map(|point| self.buffer.get_pixel(point));
+        self.underline_overflow.save(x, y, area, pixels);
+    }
+
+    /// Allocates the storage for pixels below overflowing underlines of the grid.
+    #[cfg(feature = "framebuffer")]
+    fn reset_underline_overflow(&mut self) {
+        let font = &self.font_regular;
+        let cell_size = self.cell_size();
+        let bottom = self.glyph_offset() + font.underline.offset + font.underline.height;
+        let height = if self.clip_to_cell || self.draws_manual_underline() {
+            0
+        } else {
+            bottom.saturating_sub(cell_size.height)
+        };
+        self.underline_overflow = UnderlineOverflow::new(
+            self.columns_rows,
+            geometry::Size::new(cell_size.width, height),
+            self.background_color(),
+        );
+    }
+
+    #[cfg(feature = "framebuffer")]
+    fn track_reversed_cell(&mut self, x: u16, y: u16, cell: &ratatui_core::buffer::Cell) {
+        if cell.modifier.contains(style::Modifier::REVERSED) {
+            self.reversed_cells.insert((x, y));
+        } else {
+            self.reversed_cells.remove(&(x, y));
+        }
+    }
+
+    #[cfg(feature = "blink")]
+    fn redraw_blink_cells(&mut self) -> Result<()> {
+        if self.blink_cells.is_empty() {
+            return Ok(());
+        }
+        let cells = core::mem::take(&mut self.blink_cells);
+        for (&(x, y), cell) in &cells {
+            self.draw_cell(x, y, cell)?;
+        }
+        self.blink_cells = cells;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        embedded_graphics::{
+            mock_display::MockDisplay,
+            mono_font::{MonoTextStyle, ascii::FONT_4X6},
+            pixelcolor::{Rgb565, Rgb888, RgbColor},
+            prelude::*,
+            text::{Alignment, LineHeight, Text, TextStyleBuilder},
+        },
+        ratatui::Terminal,
+        rstest::{fixture, rstest},
+    };
+
+    #[fixture]
+    fn display0() -> MockDisplay<Rgb888> {
+        let mut d = MockDisplay::new();
+        d.set_allow_overdraw(true);
+        d
+    }
+
+    /// Error returned by a failing [`TestDisplay`].
+    #[derive(Debug)]
+    enum BusError {
+        Timeout,
+    }
+
+    /// A display that draws to a borrowed [`MockDisplay`], counting and recording
+    /// the transfers made to it.
+    struct TestDisplay<'a, C: PixelColor = Rgb888> {
+        display: &'a mut MockDisplay<C>,
+        /// Reported area of the display, pixels outside of it are dropped.
+        area: Rectangle,
+        /// Number of `draw_iter`, `fill_contiguous` and `fill_solid` calls.
+        calls: usize,
+        /// Number of pixels transferred by those calls.
+        pixels: usize,
+        /// Areas passed to `fill_contiguous`.
+        fills: alloc::vec::Vec<Rectangle>,
+        /// Whether `fill_contiguous` may be called.
+        contiguous: bool,
+        /// Number of upcoming calls that fail.
+        failures: usize,
+    }
+
+    impl<'a, C: PixelColor> TestDisplay<'a, C> {
+        fn new(display: &'a mut MockDisplay<C>) -> Self {
+            display.set_allow_overdraw(true);
+            Self {
+                area: display.bounding_box(),
+                display,
+                calls: 0,
+                pixels: 0,
+                fills: alloc::vec::Vec::new(),
+                contiguous: true,
+                failures: 0,
+            }
+        }
+
+        fn transfer(
+            &mut self,
+            pixels: impl IntoIterator<Item = Pixel<C>>,
+        ) -> core::result::Result<(), BusError> {
+            self.calls += 1;
+            if self.failures > 0 {
+                self.failures -= 1;
+                return Err(BusError::Timeout);
+            }
+            let area = self.area;
+            let count = &mut self.pixels;
+            let pixels = pixels
+                .into_iter()
+                .filter(|pixel| area.contains(pixel.0))
+                .inspect(|_| *count += 1);
+            self.display
+                .draw_iter(pixels)
+                .map_err(|never| match never {})
+        }
+    }
+
+    impl<C: PixelColor> Dimensions for TestDisplay<'_, C> {
+        fn bounding_box(&self) -> Rectangle {
+            self.area
+        }
+    }
+
+    impl<C: PixelColor> DrawTarget for TestDisplay<'_, C> {
+        type Color = C;
+        type Error = BusError;
+
+        fn draw_iter<I>(&mut self, pixels: I) -> core::result::Result<(), Self::Error>
+        where
+            I: IntoIterator<Item = Pixel<Self::Color>>,
+        {
+            self.transfer(pixels)
+        }
+
+        fn fill_contiguous<I>(
+            &mut self,
+            area: &Rectangle,
+            colors: I,
+        ) -> core::result::Result<(), Self::Error>
+        where
+            I: IntoIterator<Item = Self::Color>,
+        {
+            assert!(self.contiguous, "fill_contiguous must not be called");
+            self.fills.push(*area);
+            self.transfer(area.points().zip(colors).map(|(p, c)| Pixel(p, c)))
+        }
+
+        fn fill_solid(
+            &mut self,
+            area: &Rectangle,
+            color: Self::Color,
+        ) -> core::result::Result<(), Self::Error> {
+            self.transfer(area.points().map(|p| Pixel(p, color)))
+        }
+    }
+
+    #[cfg(feature = "framebuffer")]
+    #[rstest]
+    fn renders_offscreen_into_heap_buffer(mut display0: MockDisplay<Rgb888>) {
+        let render = |frame: &mut ratatui::Frame| {
+            frame.render_widget(ratatui::text::Line::from("Hi"), frame.area());
+        };
+        let mut buffer = crate::framebuffer::Framebuffer::<Rgb888>::new(
+            display0.bounding_box(),
+            ColorTheme::default(),
+        );
+        Terminal::new(EmbeddedBackend::new(
+            &mut buffer,
+            EmbeddedBackendConfig {
+                font_regular: FONT_4X6,
+                ..Default::default()
+            },
+        ))
+        .expect("to create terminal")
+        .draw(render)
+        .expect("to draw");
+        Terminal::new(EmbeddedBackend::new(
+            &mut display0,
+            EmbeddedBackendConfig {
+                font_regular: FONT_4X6,
+                ..Default::default()
+            },
+        ))
+        .expect("to create terminal")
+        .draw(render)
+        .expect("to draw");
+
+        assert_eq!(buffer.get_pixel(Point::new(0, 1)), Some(Rgb888::WHITE));
+        for point in display0.bounding_box().points() {
+            assert_eq!(
+                buffer.get_pixel(point),
+                display0.get_pixel(point),
+                "{point:?}"
+            );
+        }
+    }
+
+    #[cfg(feature = "framebuffer")]
+    #[rstest]
+    fn render_buffer_matches_terminal(
+        mut display0: MockDisplay<Rgb888>,
+        mut display1: MockDisplay<Rgb888>,
+    ) {
+        use ratatui::buffer::Buffer;
+        use ratatui::style::Style;
+
+        let config = || EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            ..Default::default()
+        };
+        let mut buffer = Buffer::empty(layout::Rect::new(0, 0, 16, 10));
+        buffer.set_string(1, 1, "Hi", Style::new().red().on_blue());
+        buffer.set_string(3, 5, "there", Style::new().bold());
+        EmbeddedBackend::new(&mut display0, config())
+            .render_buffer(&buffer)
+            .unwrap();
+
+        Terminal::new(EmbeddedBackend::new(&mut display1, config()))
+            .expect("to create terminal")
+            .draw(|frame| frame.buffer_mut().merge(&buffer))
+            .expect("to draw");
+
+        assert_eq!(
+            display0.get_pixel(Point::new(7, 11)),
+            Some(ColorTheme::default().blue)
+        );
+        for point in display0.bounding_box().points() {
+            assert_eq!(
+                display0.get_pixel(point),
+                display1.get_pixel(point),
+                "{point:?}"
+            );
+        }
+    }
+
+    #[fixture]
+    fn display1() -> MockDisplay<Rgb888> {
+        display0()
+    }
+
+    #[rstest]
+    fn renders_direct_as_expected(
+        mut display0: MockDisplay<Rgb888>,
+        mut display1: MockDisplay<Rgb888>,
+    ) {
+        let config = || EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            font_bold: None,
+            vertical_alignment: TerminalAlignment::Start,
+            horizontal_alignment: TerminalAlignment::Start,
+            ..Default::default()
+        };
+
+        //render "T" via ratatui and then " est" directly to the display retrieved from the backend
+        {
+            let backend = EmbeddedBackend::new(&mut display0, config());
+            let mut terminal = Terminal::new(backend).expect("to create terminal");
+            terminal
+                .draw(|frame| {
+                    use ratatui::text::Line;
+                    let content = Line::from("T").left_aligned();
+                    frame.render_widget(content, frame.area());
+                })
+                .expect("to draw");
+
+            let display = terminal.backend_mut().display_mut();
+
+            let text = {
+                let text_style = TextStyleBuilder::new()
+                    .alignment(Alignment::Left)
+                    .line_height(LineHeight::Percent(100))
+                    .baseline(embedded_graphics::text::Baseline::Top)
+                    .build();
+
+                Text::with_text_style(
+                    " est",
+                    Point::new(0, 0),
+                    MonoTextStyle::new(&FONT_4X6, Rgb888::WHITE),
+                    text_style,
+                )
+            };
+            text.draw(display).unwrap();
+        }
+
+        //render "Test" via ratatui
+        {
+            let backend = EmbeddedBackend::new(&mut display1, config());
+            let mut terminal = Terminal::new(backend).expect("to create terminal");
+
+            terminal
+                .draw(|frame| {
+                    use ratatui::text::Line;
+                    let content = Line::from("Test").left_aligned();
+                    frame.render_widget(content, frame.area());
+                })
+                .expect("to draw");
+        }
+
         display0.assert_eq(&display1);
     }
+
+    #[cfg(feature = "framebuffer")]
+    #[rstest]
+    fn primed_buffer_is_preserved_until_overwritten(mut display0: MockDisplay<Rgb888>) {
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display0, config);
+        backend.prime_buffer(core::iter::repeat(Rgb888::RED));
+        let mut terminal = Terminal::new(backend).expect("to create terminal");
+        terminal
+            .draw(|frame| {
+                use ratatui::text::Line;
+                frame.render_widget(Line::from("T"), frame.area());
+            })
+            .expect("to draw");
+
+        let display = terminal.backend().display();
+        // The drawn cell replaces the splash...
+        assert_eq!(display.get_pixel(Point::new(3, 0)), Some(Rgb888::BLACK));
+        // ...while untouched cells keep the seeded content.
+        assert_eq!(display.get_pixel(Point::new(4, 0)), Some(Rgb888::RED));
+        assert_eq!(display.get_pixel(Point::new(63, 63)), Some(Rgb888::RED));
+    }
+
+    #[rstest]
+    fn underline_uses_default_underline_color(mut display0: MockDisplay<Rgb888>) {
+        let config = EmbeddedBackendConfig {
+            font_regular: embedded_graphics::mono_font::ascii::FONT_6X10,
+            default_underline_color: Some(Rgb888::GREEN),
+            ..Default::default()
+        };
+        let backend = EmbeddedBackend::new(&mut display0, config);
+        let mut terminal = Terminal::new(backend).expect("to create terminal");
+        terminal
+            .draw(|frame| {
+                use ratatui::style::Stylize;
+                use ratatui::text::Line;
+                frame.render_widget(Line::from(" ".underlined()), frame.area());
+            })
+            .expect("to draw");
+
+        let display = terminal.backend().display();
+        let underlined = (0..6)
+            .flat_map(|x| (0..10).map(move |y| Point::new(x, y)))
+            .filter(|&p| display.get_pixel(p) == Some(Rgb888::GREEN))
+            .count();
+        assert_eq!(underlined, 6);
+    }
+
+    #[cfg(feature = "strikethrough-color")]
+    #[rstest]
+    fn strikethrough_uses_strikethrough_color(mut display0: MockDisplay<Rgb888>) {
+        let config = EmbeddedBackendConfig {
+            font_regular: embedded_graphics::mono_font::ascii::FONT_6X10,
+            strikethrough_color: Some(Rgb888::GREEN),
+            ..Default::default()
+        };
+        let backend = EmbeddedBackend::new(&mut display0, config);
+        let mut terminal = Terminal::new(backend).expect("to create terminal");
+        terminal
+            .draw(|frame| {
+                use ratatui::style::Stylize;
+                use ratatui::text::Line;
+                frame.render_widget(Line::from(" ".crossed_out().red()), frame.area());
+            })
+            .expect("to draw");
+
+        let display = terminal.backend().display();
+        let offset = embedded_graphics::mono_font::ascii::FONT_6X10
+            .strikethrough
+            .offset as i32;
+        for x in 0..6 {
+            assert_eq!(
+                display.get_pixel(Point::new(x, offset)),
+                Some(Rgb888::GREEN),
+                "pixel {x}"
+            );
+        }
+    }
+
+    #[rstest]
+    #[case::regular_font(None)]
+    #[case::narrow_bold_font(Some(embedded_graphics::mono_font::ascii::FONT_5X8))]
+    fn underline_is_continuous_across_cells(
+        mut display0: MockDisplay<Rgb888>,
+        #[case] font_bold: Option<MonoFont<'static>>,
+    ) {
+        use ratatui::style::Stylize;
+        use ratatui::text::Line;
+
+        let regular = embedded_graphics::mono_font::ascii::FONT_6X10;
+        let config = EmbeddedBackendConfig {
+            font_regular: regular,
+            font_bold,
+            default_underline_color: Some(Rgb888::GREEN),
+            ..Default::default()
+        };
+        let backend = EmbeddedBackend::new(&mut display0, config);
+        let mut terminal = Terminal::new(backend).expect("to create terminal");
+        terminal
+            .draw(|frame| frame.render_widget(Line::from("abc".underlined().bold()), frame.area()))
+            .expect("to draw");
+
+        let row = font_bold.unwrap_or(regular).underline.offset as i32;
+        let display = terminal.backend().display();
+        for x in 0..18 {
+            assert_eq!(
+                display.get_pixel(Point::new(x, row)),
+                Some(Rgb888::GREEN),
+                "pixel {x}"
+            );
+        }
+    }
+
+    #[rstest]
+    #[case::built_in(false)]
+    #[case::manual(true)]
+    fn underline_draws_bottom_line(
+        mut display0: MockDisplay<Rgb888>,
+        #[case] manual_underline: bool,
+    ) {
+        use ratatui::style::Stylize;
+        use ratatui::text::Line;
+
+        let font = embedded_graphics::mono_font::ascii::FONT_6X10;
+        let rows = if manual_underline {
+            8..10
+        } else {
+            let offset = font.underline.offset as i32;
+            offset..offset + font.underline.height as i32
+        };
+        let config = EmbeddedBackendConfig {
+            font_regular: font,
+            default_underline_color: Some(Rgb888::GREEN),
+            manual_underline,
+            underline_thickness: 2,
+            ..Default::default()
+        };
+        let backend = EmbeddedBackend::new(&mut display0, config);
+        let mut terminal = Terminal::new(backend).expect("to create terminal");
+        terminal
+            .draw(|frame| frame.render_widget(Line::from("  ".underlined()), frame.area()))
+            .expect("to draw");
+
+        let display = terminal.backend().display();
+        for y in 0..10 {
+            for x in 0..12 {
+                let green = display.get_pixel(Point::new(x, y)) == Some(Rgb888::GREEN);
+                assert_eq!(green, rows.contains(&y), "pixel ({x}, {y})");
+            }
+        }
+    }
+
+    #[rstest]
+    fn double_underline_draws_two_rows(mut display0: MockDisplay<Rgb888>) {
+        use ratatui::style::Stylize;
+        use ratatui::text::Line;
+
+        let config = EmbeddedBackendConfig {
+            font_regular: embedded_graphics::mono_font::ascii::FONT_6X10,
+            default_underline_color: Some(Rgb888::GREEN),
+            double_underline: true,
+            ..Default::default()
+        };
+        let backend = EmbeddedBackend::new(&mut display0, config);
+        let mut terminal = Terminal::new(backend).expect("to create terminal");
+        terminal
+            .draw(|frame| frame.render_widget(Line::from("  ".underlined()), frame.area()))
+            .expect("to draw");
+
+        let display = terminal.backend().display();
+        for y in 0..10 {
+            for x in 0..12 {
+                let green = display.get_pixel(Point::new(x, y)) == Some(Rgb888::GREEN);
+                assert_eq!(green, y == 7 || y == 9, "pixel ({x}, {y})");
+            }
+        }
+    }
+
+    #[cfg(feature = "cell-cache")]
+    #[rstest]
+    fn grid_text_reports_drawn_cells() {
+        let mut display = MockDisplay::<Rgb888>::new();
+        display.set_allow_overdraw(true);
+        let config = EmbeddedBackendConfig {
+            font_regular: embedded_graphics::mono_font::ascii::FONT_10X20,
+            ..Default::default()
+        };
+        let backend = EmbeddedBackend::new(&mut display, config);
+        let mut terminal = Terminal::new(backend).expect("to create terminal");
+        terminal
+            .draw(|frame| {
+                use ratatui::layout::Rect;
+                use ratatui::text::Line;
+                frame.render_widget(Line::from("Hi"), Rect::new(1, 1, 2, 1));
+            })
+            .expect("to draw");
+
+        assert_eq!(terminal.backend().grid_text(), "      \n Hi   \n      ");
+    }
+
+    #[rstest]
+    fn flush_context_reports_full_refresh_after_clear(mut display0: MockDisplay<Rgb888>) {
+        use alloc::rc::Rc;
+        use core::cell::RefCell;
+
+        let contexts = Rc::new(RefCell::new(alloc::vec::Vec::new()));
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            flush_callback: Box::new({
+                let contexts = contexts.clone();
+                move |_: &mut MockDisplay<Rgb888>, context: &FlushContext| {
+                    contexts.borrow_mut().push(*context);
+                }
+            }),
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display0, config);
+        backend.hide_cursor().unwrap();
+        let screen = backend.display().bounding_box();
+        backend.flush().unwrap();
+        backend
+            .draw([(1, 1, &ratatui_core::buffer::Cell::new("x"))].into_iter())
+            .unwrap();
+        backend.flush().unwrap();
+        backend.clear().unwrap();
+        backend.flush().unwrap();
+
+        // Without the framebuffer, every flush reports the whole display as dirty.
+        let cell = if cfg!(feature = "framebuffer") {
+            Rectangle::new(Point::new(4, 6), Size::new(4, 6))
+        } else {
+            screen
+        };
+        let flushes =
+            [(true, screen), (false, cell), (true, screen)].map(|(full_refresh, dirty_rect)| {
+                FlushContext {
+                    full_refresh,
+                    dirty_rect,
+                }
+            });
+        assert_eq!(*contexts.borrow(), flushes);
+    }
+
+    #[cfg(feature = "framebuffer")]
+    #[rstest]
+    #[case::cursor_drawn_before(true, Rectangle::new(Point::zero(), Size::new(12, 12)))]
+    #[case::cursor_drawn_after(false, Rectangle::new(Point::zero(), Size::new(4, 6)))]
+    fn flush_callback_receives_dirty_rect(
+        mut display0: MockDisplay<Rgb888>,
+        #[case] cursor_before_callback: bool,
+        #[case] expected: Rectangle,
+    ) {
+        use alloc::rc::Rc;
+        use core::cell::Cell;
+
+        let dirty_rect = Rc::new(Cell::new(None));
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            cursor_before_callback,
+            flush_callback: Box::new({
+                let dirty_rect = dirty_rect.clone();
+                move |_: &mut MockDisplay<Rgb888>, context: &FlushContext| {
+                    dirty_rect.set(Some(context.dirty_rect));
+                }
+            }),
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display0, config);
+        backend.show_cursor().unwrap();
+        backend.flush().unwrap();
+
+        // The old cursor cell is restored and the cursor drawn over the new one.
+        backend
+            .set_cursor_position(layout::Position::new(2, 1))
+            .unwrap();
+        backend.flush().unwrap();
+
+        assert_eq!(dirty_rect.get(), Some(expected));
+    }
+
+    #[rstest]
+    #[case(true, true)]
+    #[case(false, false)]
+    fn cursor_drawn_relative_to_flush_callback(
+        mut display0: MockDisplay<Rgb888>,
+        #[case] cursor_before_callback: bool,
+        #[case] cursor_seen: bool,
+    ) {
+        use alloc::rc::Rc;
+        use core::cell::Cell;
+
+        let seen = Rc::new(Cell::new(None));
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            cursor: CursorConfig {
+                style: crate::cursor::CursorStyle::Underline,
+                blink: false,
+                color: Rgb888::GREEN,
+                ..Default::default()
+            },
+            cursor_before_callback,
+            flush_callback: Box::new({
+                let seen = seen.clone();
+                move |d: &mut MockDisplay<Rgb888>, _: &FlushContext| {
+                    seen.set(Some(d.get_pixel(Point::new(0, 5)) == Some(Rgb888::GREEN)));
+                }
+            }),
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display0, config);
+        backend.show_cursor().unwrap();
+        backend.flush().unwrap();
+
+        assert_eq!(seen.get(), Some(cursor_seen));
+        assert_eq!(
+            backend.display().get_pixel(Point::new(0, 5)),
+            Some(Rgb888::GREEN)
+        );
+    }
+
+    #[rstest]
+    #[case::block(
+        FONT_4X6,
+        crate::CursorStyle::Block,
+        Rectangle::new(Point::new(4, 6), Size::new(4, 6))
+    )]
+    #[case::bar(
+        FONT_4X6,
+        crate::CursorStyle::Bar,
+        Rectangle::new(Point::new(4, 6), Size::new(1, 6))
+    )]
+    #[case::half_block(
+        FONT_4X6,
+        crate::CursorStyle::HalfBlock,
+        Rectangle::new(Point::new(4, 9), Size::new(4, 3))
+    )]
+    #[case::wide_bar(
+        embedded_graphics::mono_font::ascii::FONT_10X20,
+        crate::CursorStyle::Bar,
+        Rectangle::new(Point::new(10, 20), Size::new(2, 20))
+    )]
+    #[case::custom_without_renderer(
+        FONT_4X6,
+        crate::CursorStyle::Custom,
+        Rectangle::new(Point::new(4, 11), Size::new(4, 1))
+    )]
+    fn solid_cursor_styles(
+        mut display0: MockDisplay<Rgb888>,
+        #[case] font: MonoFont<'static>,
+        #[case] style: crate::CursorStyle,
+        #[case] expected: Rectangle,
+    ) {
+        let config = EmbeddedBackendConfig {
+            font_regular: font,
+            cursor: CursorConfig {
+                style,
+                blink: false,
+                color: Rgb888::GREEN,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display0, config);
+        backend.set_cursor_position((1, 1)).unwrap();
+        backend.show_cursor().unwrap();
+        backend.flush().unwrap();
+
+        let display = backend.display();
+        let cell = Rectangle::new(
+            Point::new(
+                font.character_size.width as i32,
+                font.character_size.height as i32,
+            ),
+            font.character_size,
+        );
+        for point in cell.points() {
+            let cursor = display.get_pixel(point) == Some(Rgb888::GREEN);
+            assert_eq!(cursor, expected.contains(point), "pixel {point:?}");
+        }
+    }
+
+    #[cfg(feature = "framebuffer")]
+    #[rstest]
+    fn clears_reveal_background_layer(mut display0: MockDisplay<Rgb888>) {
+        use ratatui_core::buffer::Cell;
+
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display0, config);
+        backend.background_layer_mut().clear(Rgb888::GREEN).unwrap();
+
+        let mut cell = Cell::new(" ");
+        cell.bg = style::Color::Red;
+        backend
+            .draw([(0, 0, &cell), (0, 1, &cell)].into_iter())
+            .unwrap();
+        backend.flush().unwrap();
+        let red = ColorTheme::default().red;
+        assert_eq!(backend.display().get_pixel(Point::new(0, 0)), Some(red));
+
+        backend.clear_region(ClearType::CurrentLine).unwrap();
+        backend.flush().unwrap();
+        let display = backend.display();
+        assert_eq!(display.get_pixel(Point::new(0, 0)), Some(Rgb888::GREEN));
+        assert_eq!(display.get_pixel(Point::new(0, 6)), Some(red));
+
+        // Cleared cells pick up later changes to the layer, drawn cells keep covering it.
+        backend.background_layer_mut().clear(Rgb888::BLUE).unwrap();
+        backend.flush().unwrap();
+        let display = backend.display();
+        assert_eq!(display.get_pixel(Point::new(0, 0)), Some(Rgb888::BLUE));
+        assert_eq!(display.get_pixel(Point::new(0, 6)), Some(red));
+    }
+
+    #[rstest]
+    fn custom_cursor_uses_renderer(mut display0: MockDisplay<Rgb888>) {
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            cursor: CursorConfig {
+                style: crate::CursorStyle::Custom,
+                blink: false,
+                ..Default::default()
+            },
+            cursor_renderer: Some(Box::new(
+                |display: &mut CursorTarget<'_, MockDisplay<Rgb888>>, top_left, w, h| {
+                    assert_eq!((top_left, w, h), (Point::new(8, 6), 4, 6));
+                    display.fill_solid(
+                        &Rectangle::new(top_left + Point::new(1, 2), Size::new(2, 2)),
+                        Rgb888::GREEN,
+                    )
+                },
+            )),
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display0, config);
+        backend.set_cursor_position((2, 1)).unwrap();
+        backend.show_cursor().unwrap();
+        backend.flush().unwrap();
+
+        let display = backend.display();
+        assert_eq!(display.get_pixel(Point::new(9, 8)), Some(Rgb888::GREEN));
+        assert_ne!(display.get_pixel(Point::new(8, 11)), Some(Rgb888::GREEN));
+    }
+
+    #[cfg(feature = "cell-cache")]
+    #[rstest]
+    fn disabled_region_uses_disabled_colors(mut display0: MockDisplay<Rgb888>) {
+        let theme = ColorTheme {
+            disabled_fg: Rgb888::new(10, 20, 30),
+            disabled_bg: Rgb888::new(1, 2, 3),
+            ..ColorTheme::ansi()
+        };
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            color_theme: theme,
+            ..Default::default()
+        };
+        let backend = EmbeddedBackend::new(&mut display0, config);
+        let mut terminal = Terminal::new(backend).expect("to create terminal");
+        terminal
+            .draw(|frame| {
+                use ratatui::text::Line;
+                frame.render_widget(Line::from("Hi"), frame.area());
+            })
+            .expect("to draw");
+        let backend = terminal.backend_mut();
+        backend
+            .draw_disabled_region(ratatui::layout::Rect::new(0, 0, 1, 1))
+            .unwrap();
+        backend.flush().unwrap();
+
+        let display = backend.display();
+        let cell_pixels = |x0: i32| {
+            (x0..x0 + 4)
+                .flat_map(|x| (0..6).map(move |y| Point::new(x, y)))
+                .filter_map(|p| display.get_pixel(p))
+                .collect::<alloc::vec::Vec<_>>()
+        };
+        let disabled = cell_pixels(0);
+        assert!(disabled.contains(&theme.disabled_fg));
+        assert!(disabled.contains(&theme.disabled_bg));
+        assert!(!disabled.contains(&Rgb888::WHITE));
+        // Cells outside of the region are untouched.
+        assert!(cell_pixels(4).contains(&Rgb888::WHITE));
+
+        // A clear restores the colors of the region.
+        terminal.clear().expect("to clear");
+        terminal
+            .draw(|frame| {
+                use ratatui::text::Line;
+                frame.render_widget(Line::from("Hi"), frame.area());
+            })
+            .expect("to draw");
+        let display = terminal.backend().display();
+        assert!(
+            (0..4)
+                .flat_map(|x| (0..6).map(move |y| Point::new(x, y)))
+                .all(|p| display.get_pixel(p) != Some(theme.disabled_bg))
+        );
+    }
+
+    #[cfg(feature = "framebuffer")]
+    #[rstest]
+    fn native_block_elements_fill_sub_cell(mut display0: MockDisplay<Rgb888>) {
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            native_block_elements: true,
+            ..Default::default()
+        };
+        let backend = EmbeddedBackend::new(&mut display0, config);
+        let mut terminal = Terminal::new(backend).expect("to create terminal");
+        terminal
+            .draw(|frame| {
+                use ratatui::layout::Rect;
+                use ratatui::widgets::Gauge;
+                // 5 cells at 30% gives 1.5 cells: a full block followed by a half block.
+                let gauge = Gauge::default().ratio(0.3).label("").use_unicode(true);
+                frame.render_widget(gauge, Rect::new(0, 0, 5, 1));
+            })
+            .expect("to draw");
+
+        let display = terminal.backend().display();
+        for y in 0..6 {
+            for x in 0..20 {
+                let expected = if x < 6 { Rgb888::WHITE } else { Rgb888::BLACK };
+                assert_eq!(
+                    display.get_pixel(Point::new(x, y)),
+                    Some(expected),
+                    "({x}, {y})"
+                );
+            }
+        }
+    }
+
+    #[cfg(feature = "framebuffer")]
+    #[rstest]
+    fn native_block_elements_draw_line_gauge(mut display0: MockDisplay<Rgb888>) {
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            native_block_elements: true,
+            ..Default::default()
+        };
+        let backend = EmbeddedBackend::new(&mut display0, config);
+        let mut terminal = Terminal::new(backend).expect("to create terminal");
+        terminal
+            .draw(|frame| {
+                use ratatui::layout::Rect;
+                use ratatui::widgets::LineGauge;
+                // 9 cells after the label at 50% gives 4 filled cells, rounded down.
+                let gauge = LineGauge::default()
+                    .ratio(0.5)
+                    .label("")
+                    .filled_symbol("▄")
+                    .unfilled_symbol(" ");
+                frame.render_widget(gauge, Rect::new(0, 0, 10, 1));
+            })
+            .expect("to draw");
+
+        let display = terminal.backend().display();
+        for y in 0..6 {
+            for x in 0..40 {
+                let filled = (4..20).contains(&x) && y >= 3;
+                let expected = if filled { Rgb888::WHITE } else { Rgb888::BLACK };
+                assert_eq!(
+                    display.get_pixel(Point::new(x, y)),
+                    Some(expected),
+                    "({x}, {y})"
+                );
+            }
+        }
+    }
+
+    #[cfg(feature = "wide-chars")]
+    #[rstest]
+    fn wide_chars_skip_continuation_cell(mut display0: MockDisplay<Rgb888>) {
+        use ratatui_core::buffer::Cell;
+
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display0, config);
+        let wide = Cell::new("中");
+        let mut continuation = Cell::new(" ");
+        continuation.bg = style::Color::Red;
+        let mut next = Cell::new(" ");
+        next.bg = style::Color::Blue;
+        backend
+            .draw([(0, 0, &wide), (1, 0, &continuation), (2, 0, &next)].into_iter())
+            .unwrap();
+        backend.flush().unwrap();
+
+        let theme = ColorTheme::default();
+        let display = backend.display();
+        for point in Rectangle::new(Point::new(4, 0), Size::new(4, 6)).points() {
+            assert_ne!(display.get_pixel(point), Some(theme.red), "pixel {point:?}");
+        }
+        assert_eq!(display.get_pixel(Point::new(8, 0)), Some(theme.blue));
+    }
+
+    #[rstest]
+    fn first_flush_shows_theme_background(mut display0: MockDisplay<Rgb888>) {
+        use ratatui_core::buffer::Cell;
+
+        let theme = ColorTheme::tokyo_night();
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            color_theme: theme,
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display0, config);
+        #[cfg(feature = "framebuffer")]
+        {
+            backend.flush().unwrap();
+            let display = backend.display();
+            assert!(
+                display
+                    .bounding_box()
+                    .points()
+                    .all(|p| display.get_pixel(p) == Some(theme.background))
+            );
+        }
+
+        let mut cell = Cell::new(" ");
+        cell.bg = style::Color::Red;
+        backend.draw([(0, 0, &cell)].into_iter()).unwrap();
+        backend.flush().unwrap();
+        backend.present_cleared().unwrap();
+        let display = backend.display();
+        assert!(
+            display
+                .bounding_box()
+                .points()
+                .all(|p| display.get_pixel(p) == Some(theme.background))
+        );
+    }
+
+    #[rstest]
+    fn scale_enlarges_cells(mut display0: MockDisplay<Rgb888>) {
+        use ratatui_core::buffer::Cell;
+
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            scale: 2,
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display0, config);
+        assert_eq!(backend.size().unwrap(), layout::Size::new(8, 5));
+
+        let mut cell = Cell::new(" ");
+        cell.bg = style::Color::Red;
+        backend.draw([(1, 1, &cell)].into_iter()).unwrap();
+        backend.flush().unwrap();
+
+        let red = ColorTheme::default().red;
+        let display = backend.display();
+        let cell_area = Rectangle::new(Point::new(8, 12), Size::new(8, 12));
+        for point in Rectangle::new(Point::new(4, 8), Size::new(16, 20)).points() {
+            let filled = display.get_pixel(point) == Some(red);
+            assert_eq!(filled, cell_area.contains(point), "pixel {point:?}");
+        }
+    }
+
+    #[rstest]
+    fn cursor_pixel_position_includes_alignment_offset(mut display0: MockDisplay<Rgb888>) {
+        let config = EmbeddedBackendConfig {
+            font_regular: embedded_graphics::mono_font::ascii::FONT_6X10,
+            horizontal_alignment: TerminalAlignment::Center,
+            vertical_alignment: TerminalAlignment::End,
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display0, config);
+        backend.set_cursor_position((3, 2)).unwrap();
+
+        // 64 % 6 = 4 spare columns split evenly, 64 % 10 = 4 spare rows on top.
+        assert_eq!(backend.cursor_pixel_position(), Point::new(2 + 18, 4 + 20));
+    }
+
+    #[rstest]
+    #[case::top_left(Point::new(2, 4), Some((0, 0)))]
+    #[case::last_pixel_of_cell(Point::new(7, 13), Some((0, 0)))]
+    #[case::next_cell(Point::new(8, 14), Some((1, 1)))]
+    #[case::bottom_right(Point::new(61, 63), Some((9, 5)))]
+    #[case::left_margin(Point::new(1, 4), None)]
+    #[case::top_margin(Point::new(2, 3), None)]
+    #[case::right_margin(Point::new(62, 63), None)]
+    #[case::off_display(Point::new(-5, 100), None)]
+    fn pixel_to_cell_applies_alignment_offset(
+        mut display0: MockDisplay<Rgb888>,
+        #[case] point: Point,
+        #[case] expected: Option<(u16, u16)>,
+    ) {
+        let config = EmbeddedBackendConfig {
+            font_regular: embedded_graphics::mono_font::ascii::FONT_6X10,
+            horizontal_alignment: TerminalAlignment::Center,
+            vertical_alignment: TerminalAlignment::End,
+            ..Default::default()
+        };
+        let backend = EmbeddedBackend::new(&mut display0, config);
+        assert_eq!(backend.pixel_to_cell(point), expected);
+    }
+
+    #[rstest]
+    #[case::deg0(Rotation::Deg0, (16, 5), Rectangle::new(Point::new(0, 0), Size::new(4, 6)))]
+    #[case::deg90(Rotation::Deg90, (8, 10), Rectangle::new(Point::new(58, 0), Size::new(6, 4)))]
+    #[case::deg180(Rotation::Deg180, (16, 5), Rectangle::new(Point::new(60, 26), Size::new(4, 6)))]
+    #[case::deg270(Rotation::Deg270, (8, 10), Rectangle::new(Point::new(0, 28), Size::new(6, 4)))]
+    fn rotation_maps_cells_to_display(
+        mut display0: MockDisplay<Rgb888>,
+        #[case] rotation: Rotation,
+        #[case] columns_rows: (u16, u16),
+        #[case] expected: Rectangle,
+    ) {
+        use embedded_graphics::draw_target::DrawTargetExt;
+        use ratatui_core::buffer::Cell;
+
+        let mut display = display0.cropped(&Rectangle::new(Point::zero(), Size::new(64, 32)));
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            rotation,
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display, config);
+        assert_eq!(
+            backend.size().unwrap(),
+            layout::Size::new(columns_rows.0, columns_rows.1)
+        );
+        assert_eq!(backend.cell_rect(0, 0), expected);
+        assert_eq!(backend.pixel_to_cell(expected.center()), Some((0, 0)));
+
+        let mut cell = Cell::new(" ");
+        cell.bg = style::Color::Red;
+        backend.draw([(0, 0, &cell)].into_iter()).unwrap();
+        backend.flush().unwrap();
+        drop(backend);
+
+        let red = ColorTheme::default().red;
+        for point in expected.points() {
+            assert_eq!(display0.get_pixel(point), Some(red), "{point:?}");
+        }
+    }
+
+    #[rstest]
+    fn rotation_applies_to_cursor(mut display0: MockDisplay<Rgb888>) {
+        use embedded_graphics::draw_target::DrawTargetExt;
+
+        let mut display = display0.cropped(&Rectangle::new(Point::zero(), Size::new(64, 32)));
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            rotation: Rotation::Deg90,
+            cursor: CursorConfig {
+                style: crate::CursorStyle::Block,
+                blink: false,
+                color: Rgb888::GREEN,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display, config);
+        backend.show_cursor().unwrap();
+        backend
+            .set_cursor_position(layout::Position::new(1, 0))
+            .unwrap();
+        backend.flush().unwrap();
+        drop(backend);
+
+        // Columns of the rotated grid run down the right edge of the display.
+        let expected = Rectangle::new(Point::new(58, 4), Size::new(6, 4));
+        for point in expected.points() {
+            assert_eq!(display0.get_pixel(point), Some(Rgb888::GREEN), "{point:?}");
+        }
+    }
+
+    #[rstest]
+    fn rotation_applies_to_custom_cursor(mut display0: MockDisplay<Rgb888>) {
+        use embedded_graphics::draw_target::DrawTargetExt;
+
+        let mut display = display0.cropped(&Rectangle::new(Point::zero(), Size::new(64, 32)));
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            rotation: Rotation::Deg90,
+            cursor: CursorConfig {
+                style: crate::CursorStyle::Custom,
+                blink: false,
+                ..Default::default()
+            },
+            cursor_renderer: Some(Box::new(|display, top_left, w, h| {
+                display.fill_solid(
+                    &Rectangle::new(top_left, Size::new(w as u32, h as u32)),
+                    Rgb888::GREEN,
+                )
+            })),
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display, config);
+        backend.show_cursor().unwrap();
+        backend
+            .set_cursor_position(layout::Position::new(1, 0))
+            .unwrap();
+        backend.flush().unwrap();
+        drop(backend);
+
+        let expected = Rectangle::new(Point::new(58, 4), Size::new(6, 4));
+        for point in expected.points() {
+            assert_eq!(display0.get_pixel(point), Some(Rgb888::GREEN), "{point:?}");
+        }
+    }
+
+    #[rstest]
+    fn cell_rect_applies_alignment_offset(mut display0: MockDisplay<Rgb888>) {
+        let config = EmbeddedBackendConfig {
+            font_regular: embedded_graphics::mono_font::ascii::FONT_6X10,
+            horizontal_alignment: TerminalAlignment::Center,
+            vertical_alignment: TerminalAlignment::End,
+            ..Default::default()
+        };
+        let backend = EmbeddedBackend::new(&mut display0, config);
+        assert_eq!(
+            backend.cell_rect(0, 0),
+            Rectangle::new(Point::new(2, 4), Size::new(6, 10))
+        );
+        assert_eq!(
+            backend.cell_rect(9, 5),
+            Rectangle::new(Point::new(56, 54), Size::new(6, 10))
+        );
+    }
+
+    #[test]
+    fn cell_rect_scales_relative_to_display_origin() {
+        let mut mock = MockDisplay::<Rgb888>::new();
+        let mut display = TestDisplay::new(&mut mock);
+        display.area = Rectangle::new(Point::new(10, 10), Size::new(54, 54));
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            scale: 2,
+            cursor: CursorConfig {
+                style: crate::CursorStyle::Underline,
+                blink: false,
+                color: Rgb888::GREEN,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let backend = EmbeddedBackend::new(&mut display, config);
+        let mut terminal = Terminal::new(backend).expect("to create terminal");
+        terminal
+            .draw(|frame| frame.set_cursor_position((1, 1)))
+            .expect("to draw");
+
+        // The cell at (4, 6) from the origin is scaled to (8, 12) from it.
+        let cell = Rectangle::new(Point::new(18, 22), Size::new(8, 12));
+        let backend = terminal.backend();
+        assert_eq!(backend.cell_rect(1, 1), cell);
+        assert_eq!(backend.cursor_pixel_position(), cell.top_left);
+        let display = &backend.display().display;
+        assert_eq!(display.get_pixel(Point::new(18, 33)), Some(Rgb888::GREEN));
+        assert_eq!(display.get_pixel(Point::new(25, 33)), Some(Rgb888::GREEN));
+        assert_ne!(display.get_pixel(Point::new(26, 33)), Some(Rgb888::GREEN));
+        assert_ne!(display.get_pixel(Point::new(18, 32)), Some(Rgb888::GREEN));
+    }
+
+    #[rstest]
+    fn out_of_bounds_cells_are_skipped(mut display0: MockDisplay<Rgb888>) {
+        use ratatui_core::buffer::Cell;
+
+        // 64 pixels fit 10 columns of 6 pixels, leaving 4 pixels of a partial column.
+        let config = EmbeddedBackendConfig {
+            font_regular: embedded_graphics::mono_font::ascii::FONT_6X10,
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display0, config);
+        let mut cell = Cell::new("x");
+        cell.bg = style::Color::Red;
+        backend
+            .draw([(9, 0, &cell), (10, 0, &cell), (0, 6, &cell)].into_iter())
+            .unwrap();
+        backend.flush().unwrap();
+
+        let red = ColorTheme::default().red;
+        let display = backend.display();
+        assert_eq!(display.get_pixel(Point::new(54, 0)), Some(red));
+        assert_ne!(display.get_pixel(Point::new(60, 0)), Some(red));
+        assert_ne!(display.get_pixel(Point::new(0, 60)), Some(red));
+    }
+
+    #[rstest]
+    fn margin_shrinks_grid_and_shifts_cells(mut display0: MockDisplay<Rgb888>) {
+        use ratatui_core::buffer::Cell;
+
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            margin: (2, 3, 4, 5),
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display0, config);
+        assert_eq!(backend.size().unwrap(), layout::Size::new(14, 9));
+
+        let mut cell = Cell::new(" ");
+        cell.bg = style::Color::Red;
+        backend.draw([(0, 0, &cell)].into_iter()).unwrap();
+        backend.flush().unwrap();
+
+        let red = ColorTheme::default().red;
+        let display = backend.display();
+        let cell_area = Rectangle::new(Point::new(5, 2), Size::new(4, 6));
+        for point in Rectangle::new(Point::zero(), Size::new(12, 10)).points() {
+            let filled = display.get_pixel(point) == Some(red);
+            assert_eq!(filled, cell_area.contains(point), "pixel {point:?}");
+        }
+    }
+
+    #[rstest]
+    fn offset_alignment_shifts_cells(mut display0: MockDisplay<Rgb888>) {
+        use ratatui_core::buffer::Cell;
+
+        let config = EmbeddedBackendConfig {
+            font_regular: embedded_graphics::mono_font::ascii::FONT_6X10,
+            horizontal_alignment: TerminalAlignment::Offset(5),
+            vertical_alignment: TerminalAlignment::Offset(5),
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display0, config);
+        // The offset leaves 59 × 59 pixels for the grid.
+        assert_eq!(backend.size().unwrap(), layout::Size::new(9, 5));
+
+        let mut cell = Cell::new(" ");
+        cell.bg = style::Color::Red;
+        backend.draw([(0, 0, &cell)].into_iter()).unwrap();
+        backend.flush().unwrap();
+
+        let red = ColorTheme::default().red;
+        let display = backend.display();
+        let cell_area = Rectangle::new(Point::new(5, 5), Size::new(6, 10));
+        for point in Rectangle::new(Point::zero(), Size::new(12, 16)).points() {
+            let filled = display.get_pixel(point) == Some(red);
+            assert_eq!(filled, cell_area.contains(point), "pixel {point:?}");
+        }
+    }
+
+    #[rstest]
+    fn borrowing_display_is_supported(mut display0: MockDisplay<Rgb888>) {
+        let mut display = TestDisplay::new(&mut display0);
+        let backend = EmbeddedBackend::from_display(&mut display);
+        let mut terminal = Terminal::new(backend).expect("to create terminal");
+        terminal
+            .draw(|frame| frame.render_widget("Hi", frame.area()))
+            .expect("to draw");
+    }
+
+    #[rstest]
+    #[case::no_spacing(0, 0, layout::Size::new(16, 10))]
+    #[case::letter_spacing(1, 0, layout::Size::new(12, 10))]
+    #[case::line_spacing(0, 2, layout::Size::new(16, 8))]
+    #[case::both(2, 1, layout::Size::new(10, 9))]
+    fn spacing_reduces_grid_size(
+        mut display0: MockDisplay<Rgb888>,
+        #[case] letter_spacing: u16,
+        #[case] line_spacing: u16,
+        #[case] expected: layout::Size,
+    ) {
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            letter_spacing,
+            line_spacing,
+            ..Default::default()
+        };
+        let backend = EmbeddedBackend::new(&mut display0, config);
+        assert_eq!(backend.size().unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case::no_spacing(0, 0)]
+    #[case::spaced(1, 2)]
+    fn spacing_shifts_glyphs(
+        mut display0: MockDisplay<Rgb888>,
+        #[case] letter_spacing: u16,
+        #[case] line_spacing: u16,
+    ) {
+        use embedded_graphics::mono_font::MonoTextStyle;
+        use embedded_graphics::primitives::PrimitiveStyle;
+        use embedded_graphics::text::Baseline;
+        use ratatui_core::buffer::Cell;
+
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            letter_spacing,
+            line_spacing,
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display0, config);
+
+        let mut cell = Cell::new("A");
+        cell.fg = style::Color::White;
+        cell.bg = style::Color::Blue;
+        backend.draw([(1, 1, &cell)].into_iter()).unwrap();
+        backend.flush().unwrap();
+
+        // The glyph stays at the top left of its cell, and the spacing takes the
+        // cell background.
+        let theme = ColorTheme::default();
+        let mut expected = MockDisplay::<Rgb888>::new();
+        expected.set_allow_overdraw(true);
+        let cell_size = Size::new(4 + u32::from(letter_spacing), 6 + u32::from(line_spacing));
+        let cell_area = Rectangle::new(Point::zero() + cell_size, cell_size);
+        cell_area
+            .into_styled(PrimitiveStyle::with_fill(theme.blue))
+            .draw(&mut expected)
+            .unwrap();
+        let style = MonoTextStyle::new(&FONT_4X6, theme.white);
+        Text::with_baseline("A", cell_area.top_left, style, Baseline::Top)
+            .draw(&mut expected)
+            .unwrap();
+
+        for point in cell_area.points() {
+            assert_eq!(
+                backend.display().get_pixel(point),
+                expected.get_pixel(point),
+                "{point:?}"
+            );
+        }
+    }
+
+    #[rstest]
+    fn line_height_centers_glyphs_in_taller_rows(mut display0: MockDisplay<Rgb888>) {
+        use embedded_graphics::mono_font::MonoTextStyle;
+        use embedded_graphics::primitives::PrimitiveStyle;
+        use embedded_graphics::text::Baseline;
+        use ratatui_core::buffer::Cell;
+
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            line_height_percent: 150,
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display0, config);
+        assert_eq!(backend.size().unwrap(), layout::Size::new(16, 7));
+
+        let mut cell = Cell::new("A");
+        cell.fg = style::Color::White;
+        cell.bg = style::Color::Blue;
+        backend.draw([(1, 1, &cell)].into_iter()).unwrap();
+        backend.flush().unwrap();
+
+        let theme = ColorTheme::default();
+        let mut expected = MockDisplay::<Rgb888>::new();
+        expected.set_allow_overdraw(true);
+        let cell_area = Rectangle::new(Point::new(4, 9), Size::new(4, 9));
+        cell_area
+            .into_styled(PrimitiveStyle::with_fill(theme.blue))
+            .draw(&mut expected)
+            .unwrap();
+        let style = MonoTextStyle::new(&FONT_4X6, theme.white);
+        Text::with_baseline("A", Point::new(4, 10), style, Baseline::Top)
+            .draw(&mut expected)
+            .unwrap();
+
+        for point in cell_area.points() {
+            assert_eq!(
+                backend.display().get_pixel(point),
+                expected.get_pixel(point),
+                "pixel {point:?}"
+            );
+        }
+    }
+
+    #[cfg(feature = "framebuffer")]
+    #[rstest]
+    fn resize_updates_grid(mut display0: MockDisplay<Rgb888>) {
+        use ratatui::layout::{Rect, Size};
+
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            ..Default::default()
+        };
+        let backend = EmbeddedBackend::new(&mut display0, config);
+        let mut terminal = Terminal::new(backend).expect("to create terminal");
+        assert_eq!(terminal.size().unwrap(), Size::new(16, 10));
+
+        terminal
+            .backend_mut()
+            .resize(Size::new(32, 24))
+            .expect("to resize");
+        assert_eq!(terminal.backend().size().unwrap(), Size::new(8, 4));
+
+        let completed = terminal
+            .draw(|frame| {
+                use ratatui::style::Stylize;
+                use ratatui::widgets::Block;
+                frame.render_widget(Block::new().on_red(), frame.area());
+            })
+            .expect("to draw");
+        assert_eq!(completed.area, Rect::new(0, 0, 8, 4));
+
+        let display = terminal.backend().display();
+        assert_eq!(display.get_pixel(Point::new(31, 23)), Some(Rgb888::RED));
+        assert_eq!(display.get_pixel(Point::new(32, 23)), None);
+    }
+
+    #[rstest]
+    fn resize_beyond_display_fails(mut display0: MockDisplay<Rgb888>) {
+        let mut backend = EmbeddedBackend::new(&mut display0, EmbeddedBackendConfig::default());
+        let result = backend.resize(layout::Size::new(65, 32));
+        assert!(matches!(
+            result,
+            Err(crate::error::Error::DisplaySize {
+                width: 65,
+                height: 32,
+                max_width: 64,
+                max_height: 64,
+            })
+        ));
+        assert_eq!(backend.pixels, layout::Size::new(64, 64));
+    }
+
+    #[rstest]
+    fn slow_blink_border_is_static(mut display0: MockDisplay<Rgb888>) {
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            slow_display_blink_mode: SlowBlinkMode::Border,
+            ..Default::default()
+        };
+        let backend = EmbeddedBackend::new(&mut display0, config);
+        let mut terminal = Terminal::new(backend).expect("to create terminal");
+        for _ in 0..60 {
+            terminal
+                .draw(|frame| {
+                    use ratatui::style::Stylize;
+                    use ratatui::text::Line;
+                    frame.render_widget(Line::from(" ".slow_blink()), frame.area());
+                })
+                .expect("to draw");
+
+            let display = terminal.backend().display();
+            for (x, y) in [(0, 0), (3, 0), (0, 5), (3, 5), (1, 0), (0, 3)] {
+                assert_eq!(display.get_pixel(Point::new(x, y)), Some(Rgb888::WHITE));
+            }
+            assert_eq!(display.get_pixel(Point::new(1, 2)), Some(Rgb888::BLACK));
+        }
+        #[cfg(feature = "blink")]
+        assert!(terminal.backend().blink_cells.is_empty());
+    }
+
+    #[rstest]
+    // FONT_4X6 is 6 pixels tall, so the descender of "g" ends below a 4 pixel line.
+    #[case::descender(false, false, 67, true)]
+    #[case::clipped_descender(true, false, 67, false)]
+    // FONT_4X6 places its underline one pixel below the cell.
+    #[case::underline(false, true, 100, true)]
+    #[case::clipped_underline(true, true, 100, false)]
+    fn clip_to_cell_keeps_overflow_out_of_next_row(
+        mut display0: MockDisplay<Rgb888>,
+        #[case] clip_to_cell: bool,
+        #[case] underlined: bool,
+        #[case] line_height_percent: u16,
+        #[case] overflows: bool,
+    ) {
+        use ratatui::style::Stylize;
+
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            clip_to_cell,
+            line_height_percent,
+            ..Default::default()
+        };
+        let backend = EmbeddedBackend::new(&mut display0, config);
+        let cell_height = backend.cell_size().height as i32;
+        let mut terminal = Terminal::new(backend).expect("to create terminal");
+        let symbol = if underlined {
+            "g".underlined()
+        } else {
+            "g".into()
+        };
+        terminal
+            .draw(|frame| {
+                use ratatui::text::Line;
+                frame.render_widget(Line::from(symbol), frame.area());
+            })
+            .expect("to draw");
+
+        let display = terminal.backend().display();
+        let next_row_touched = (0..4).any(|x| {
+            (cell_height..cell_height + 2)
+                .any(|y| display.get_pixel(Point::new(x, y)) == Some(Rgb888::WHITE))
+        });
+        assert_eq!(next_row_touched, overflows);
+    }
+
+    #[rstest]
+    fn focus_ring_outlines_region(mut display0: MockDisplay<Rgb888>) {
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display0, config);
+        backend
+            .draw_focus_ring(ratatui::layout::Rect::new(1, 1, 3, 2), Rgb888::GREEN, 2)
+            .unwrap();
+        backend.flush().unwrap();
+
+        // 3×2 cells of FONT_4X6 cover 12×12 pixels starting at (4, 6).
+        let region = Rectangle::new(Point::new(4, 6), Size::new(12, 12));
+        let interior = Rectangle::new(Point::new(6, 8), Size::new(8, 8));
+        let display = backend.display();
+        for point in Rectangle::new(Point::zero(), Size::new(20, 20)).points() {
+            let ring = region.contains(point) && !interior.contains(point);
+            let green = display.get_pixel(point) == Some(Rgb888::GREEN);
+            assert_eq!(green, ring, "pixel {point:?}");
+        }
+    }
+
+    #[cfg(feature = "framebuffer")]
+    #[rstest]
+    fn draw_image_blits_into_region(mut display0: MockDisplay<Rgb888>) {
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display0, config);
+        // 2×2 cells of FONT_4X6 cover 8×12 pixels.
+        let checkerboard = |x: i32, y: i32| {
+            if (x + y) % 2 == 0 {
+                Rgb888::WHITE
+            } else {
+                Rgb888::RED
+            }
+        };
+        let image: alloc::vec::Vec<Rgb888> = (0..12)
+            .flat_map(|y| (0..8).map(move |x| checkerboard(x, y)))
+            .collect();
+        backend
+            .draw_image(ratatui::layout::Rect::new(1, 1, 2, 2), &image, 8)
+            .unwrap();
+        backend.flush().unwrap();
+
+        let display = backend.display();
+        for y in 0..12 {
+            for x in 0..8 {
+                assert_eq!(
+                    display.get_pixel(Point::new(4 + x, 6 + y)),
+                    Some(checkerboard(x, y))
+                );
+            }
+        }
+        assert_eq!(display.get_pixel(Point::new(12, 6)), Some(Rgb888::BLACK));
+        assert_eq!(display.get_pixel(Point::new(4, 18)), Some(Rgb888::BLACK));
+    }
+
+    #[cfg(feature = "blink")]
+    #[rstest]
+    fn blink_phase_is_preserved_across_pause(mut display0: MockDisplay<Rgb888>) {
+        let mut backend = EmbeddedBackend::new(&mut display0, EmbeddedBackendConfig::default());
+        // The default slow blink hides during the last 5 frames of each 30 frame cycle.
+        for _ in 0..27 {
+            backend.draw(core::iter::empty()).unwrap();
+        }
+        assert!(backend.blink_config.slow.is_hidden());
+
+        backend.pause_blink();
+        for _ in 0..10 {
+            backend.draw(core::iter::empty()).unwrap();
+        }
+        assert_eq!(backend.frame_count, 27);
+        assert!(backend.blink_config.slow.is_hidden());
+
+        backend.resume_blink();
+        for _ in 0..3 {
+            backend.draw(core::iter::empty()).unwrap();
+        }
+        assert_eq!(backend.frame_count, 30);
+        assert!(!backend.blink_config.slow.is_hidden());
+    }
+
+    #[cfg(feature = "blink")]
+    #[rstest]
+    fn paused_blink_keeps_blinking_cells_visible(mut display0: MockDisplay<Rgb888>) {
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            native_block_elements: true,
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display0, config);
+        let mut cell = ratatui_core::buffer::Cell::new("█");
+        cell.modifier = style::Modifier::SLOW_BLINK;
+        backend.draw(core::iter::once((0, 0, &cell))).unwrap();
+        // The default slow blink hides during the last 5 frames of each 30 frame cycle.
+        for _ in 0..26 {
+            backend.draw(core::iter::empty()).unwrap();
+        }
+        assert!(backend.blink_config.slow.is_hidden());
+
+        backend.set_blink_paused(true);
+        for _ in 0..40 {
+            backend.draw(core::iter::empty()).unwrap();
+            backend.flush().unwrap();
+            assert_eq!(
+                backend.display().get_pixel(Point::new(0, 0)),
+                Some(Rgb888::WHITE)
+            );
+        }
+        assert_eq!(backend.frame_count, 27);
+
+        backend.set_blink_paused(false);
+        backend.reset_blink();
+        backend.draw(core::iter::empty()).unwrap();
+        assert_eq!(backend.frame_count, 1);
+        assert!(!backend.blink_config.slow.is_hidden());
+    }
+
+    #[cfg(feature = "blink")]
+    #[rstest]
+    fn cursor_blinks_independently_of_text(mut display0: MockDisplay<Rgb888>) {
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            cursor: CursorConfig {
+                style: crate::cursor::CursorStyle::Block,
+                blink_timing: Some(BlinkTiming::new(2, 50)),
+                ..Default::default()
+            },
+            blink: BlinkConfig {
+                slow: BlinkTiming::new(1, 50),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display0, config);
+        backend.show_cursor().unwrap();
+
+        // At 30 fps, the cursor hides during frames 7..15 of each 15 frame cycle and
+        // slow text during frames 15..30 of each 30 frame cycle.
+        let mut states = alloc::vec::Vec::new();
+        for _ in 0..30 {
+            backend.draw(core::iter::empty()).unwrap();
+            backend.flush().unwrap();
+            let cursor_hidden = backend.cursor.config.blink_timing.unwrap().is_hidden();
+            states.push((cursor_hidden, backend.blink_config.slow.is_hidden()));
+        }
+        let frame = |n: usize| states[n - 1];
+        assert_eq!(frame(6), (false, false));
+        assert_eq!(frame(7), (true, false));
+        assert_eq!(frame(14), (true, false));
+        assert_eq!(frame(15), (false, true));
+        assert_eq!(frame(21), (false, true));
+        assert_eq!(frame(22), (true, true));
+        assert_eq!(frame(30), (false, false));
+    }
+
+    #[cfg(feature = "framebuffer")]
+    #[test]
+    fn draw_iter_flush_strategy() {
+        let mut mock = display0();
+        let mut display = TestDisplay::new(&mut mock);
+        display.contiguous = false;
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            flush_strategy: FlushStrategy::DrawIter,
+            ..Default::default()
+        };
+        let backend = EmbeddedBackend::new(&mut display, config);
+        let mut terminal = Terminal::new(backend).expect("to create terminal");
+        terminal
+            .draw(|frame| {
+                use ratatui::style::Stylize;
+                use ratatui::text::Line;
+                frame.render_widget(Line::from(" ".on_red()), frame.area());
+            })
+            .expect("to draw");
+
+        let display = &terminal.backend().display().display;
+        assert_eq!(display.get_pixel(Point::new(3, 5)), Some(Rgb888::RED));
+        assert_eq!(display.get_pixel(Point::new(4, 5)), Some(Rgb888::BLACK));
+        assert_eq!(display.get_pixel(Point::new(63, 63)), Some(Rgb888::BLACK));
+    }
+
+    #[cfg(all(feature = "scrolling-regions", feature = "framebuffer"))]
+    #[rstest]
+    #[case::up(true, [Rgb888::BLUE, Rgb888::BLACK, Rgb888::GREEN])]
+    #[case::down(false, [Rgb888::BLACK, Rgb888::RED, Rgb888::GREEN])]
+    fn scroll_region_moves_rows(
+        mut display0: MockDisplay<Rgb888>,
+        #[case] up: bool,
+        #[case] expected: [Rgb888; 3],
+    ) {
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            color_theme: ColorTheme {
+                red: Rgb888::RED,
+                green: Rgb888::GREEN,
+                blue: Rgb888::BLUE,
+                ..ColorTheme::default()
+            },
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display0, config);
+        backend.hide_cursor().unwrap();
+        let cells = [style::Color::Red, style::Color::Blue, style::Color::Green].map(|bg| {
+            let mut cell = ratatui_core::buffer::Cell::new(" ");
+            cell.bg = bg;
+            cell
+        });
+        backend
+            .draw(
+                cells
+                    .iter()
+                    .enumerate()
+                    .map(|(y, cell)| (0, y as u16, cell)),
+            )
+            .unwrap();
+
+        // Row 2 is outside the region and stays in place.
+        if up {
+            backend.scroll_region_up(0..2, 1).unwrap();
+        } else {
+            backend.scroll_region_down(0..2, 1).unwrap();
+        }
+        backend.flush().unwrap();
+
+        for (row, color) in expected.into_iter().enumerate() {
+            assert_eq!(
+                backend.display().get_pixel(Point::new(0, row as i32 * 6)),
+                Some(color),
+                "row {row}"
+            );
+        }
+    }
+
+    #[cfg(all(
+        feature = "scrolling-regions",
+        not(any(feature = "framebuffer", feature = "cell-cache"))
+    ))]
+    #[rstest]
+    fn scroll_region_without_content_clears_region(mut display0: MockDisplay<Rgb888>) {
+        display0.set_allow_overdraw(true);
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display0, config);
+        backend.hide_cursor().unwrap();
+        let mut cell = ratatui_core::buffer::Cell::new(" ");
+        cell.bg = style::Color::White;
+        backend.draw((0..3).map(|y| (0, y, &cell))).unwrap();
+
+        backend.scroll_region_up(0..2, 1).unwrap();
+        backend.flush().unwrap();
+
+        let theme = ColorTheme::default();
+        let pixel = |row: i32| backend.display().get_pixel(Point::new(0, row * 6));
+        assert_eq!(pixel(0), Some(theme.background));
+        assert_eq!(pixel(1), Some(theme.background));
+        assert_eq!(pixel(2), Some(theme.white));
+    }
+
+    #[cfg(feature = "blink")]
+    #[rstest]
+    fn fade_blink_interpolates_foreground(mut display0: MockDisplay<Rgb888>) {
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            native_block_elements: true,
+            blink: BlinkConfig {
+                style: BlinkStyle::Fade,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display0, config);
+        let mut cell = ratatui_core::buffer::Cell::new("█");
+        cell.modifier = style::Modifier::SLOW_BLINK;
+        backend.draw(core::iter::once((0, 0, &cell))).unwrap();
+        // 8 frames into the 30 frame cycle of the default slow blink.
+        for _ in 0..7 {
+            backend.draw(core::iter::empty()).unwrap();
+        }
+        assert_eq!(backend.blink_config.slow.intensity(), 119);
+        backend.flush().unwrap();
+
+        assert_eq!(
+            backend.display().get_pixel(Point::new(0, 0)),
+            Some(blend_colors(Rgb888::WHITE, Rgb888::BLACK, 255 - 119))
+        );
+    }
+
+    #[cfg(feature = "framebuffer")]
+    #[rstest]
+    #[case::unchanged(None, 0)]
+    #[case::cell_drawn(Some((1, 1)), 4 * 6)]
+    fn unchanged_flush_skips_display_writes(
+        #[case] redraw: Option<(u16, u16)>,
+        #[case] written: usize,
+    ) {
+        let mut mock = MockDisplay::<Rgb888>::new();
+        let mut display = TestDisplay::new(&mut mock);
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display, config);
+        let cell = ratatui_core::buffer::Cell::new("x");
+        backend.draw([(0, 0, &cell)].into_iter()).unwrap();
+        backend.flush().unwrap();
+        backend.display_mut().pixels = 0;
+
+        if let Some((x, y)) = redraw {
+            backend.draw([(x, y, &cell)].into_iter()).unwrap();
+        }
+        backend.flush().unwrap();
+
+        assert_eq!(backend.display().pixels, written);
+    }
+
+    #[cfg(feature = "framebuffer")]
+    #[rstest]
+    fn row_flush_skips_per_pixel_draws(
+        #[values(false, true)] row_flush: bool,
+        #[values(FlushStrategy::FillContiguous, FlushStrategy::DrawIter)]
+        flush_strategy: FlushStrategy,
+    ) {
+        use alloc::rc::Rc;
+        use core::cell::RefCell;
+        use embedded_graphics::pixelcolor::IntoStorage;
+
+        let mut mock = MockDisplay::<Rgb565>::new();
+        let mut display = TestDisplay::new(&mut mock);
+        let words = Rc::new(RefCell::new(alloc::vec::Vec::new()));
+        let row_flush_callback = row_flush.then(|| {
+            let words = words.clone();
+            Box::new(
+                move |_: &mut TestDisplay<Rgb565>, _: Rectangle, pixels: &[Rgb565]| {
+                    words
+                        .borrow_mut()
+                        .extend(pixels.iter().map(|pixel| pixel.into_storage()));
+                    Ok(())
+                },
+            ) as RowFlushFn<TestDisplay<Rgb565>, Rgb565>
+        });
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            flush_strategy,
+            row_flush_callback,
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display, config);
+        let mut cell = ratatui_core::buffer::Cell::new(" ");
+        cell.bg = style::Color::Red;
+        backend.draw([(0, 0, &cell)].into_iter()).unwrap();
+        backend.hide_cursor().unwrap();
+        backend.flush().unwrap();
+
+        // Every pixel is converted and passed through the `DrawTarget` otherwise.
+        let converted = if row_flush { 0 } else { 64 * 64 };
+        assert_eq!(backend.display().pixels, converted);
+        if row_flush {
+            let words = words.borrow();
+            let red = Rgb565::from(ColorTheme::default().red).into_storage();
+            assert_eq!(words.len(), 64 * 64);
+            assert_eq!(words[0], red);
+            assert_eq!(words[64 * 5 + 3], red);
+            assert_ne!(words[4], red);
+        }
+    }
+
+    #[rstest]
+    fn scroll_up_uses_hw_scroll(mut display0: MockDisplay<Rgb888>) {
+        use alloc::rc::Rc;
+        use core::cell::Cell;
+
+        let scrolled = Rc::new(Cell::new(None));
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            capabilities: DisplayCapabilities {
+                hw_scroll: Some(Box::new({
+                    let scrolled = scrolled.clone();
+                    move |_: &mut MockDisplay<Rgb888>, dy| scrolled.set(Some(dy))
+                })),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display0, config);
+        backend.scroll_up(2).unwrap();
+        assert_eq!(scrolled.get(), Some(12));
+    }
+
+    #[cfg(feature = "framebuffer")]
+    #[rstest]
+    fn scroll_up_moves_framebuffer(mut display0: MockDisplay<Rgb888>) {
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            ..Default::default()
+        };
+        let backend = EmbeddedBackend::new(&mut display0, config);
+        let mut terminal = Terminal::new(backend).expect("to create terminal");
+        terminal
+            .draw(|frame| {
+                use ratatui::layout::Rect;
+                use ratatui::style::Stylize;
+                use ratatui::text::Line;
+                frame.render_widget(Line::from(" ".on_red()), Rect::new(0, 1, 1, 1));
+            })
+            .expect("to draw");
+
+        let backend = terminal.backend_mut();
+        backend.scroll_up(1).unwrap();
+        backend.flush().unwrap();
+        let display = backend.display();
+        assert_eq!(display.get_pixel(Point::new(0, 0)), Some(Rgb888::RED));
+        assert_eq!(display.get_pixel(Point::new(0, 6)), Some(Rgb888::BLACK));
+    }
+
+    #[cfg(feature = "framebuffer")]
+    #[rstest]
+    fn software_invert_on_flush(mut display0: MockDisplay<Rgb888>) {
+        let mut backend = EmbeddedBackend::new(&mut display0, EmbeddedBackendConfig::default());
+        backend.set_inverted(true).unwrap();
+        backend.flush().unwrap();
+        assert_eq!(
+            backend.display().get_pixel(Point::new(0, 0)),
+            Some(Rgb888::WHITE)
+        );
+    }
+
+    #[test]
+    fn bayer_dithering_renders_mid_gray_as_checkerboard() {
+        use embedded_graphics::pixelcolor::BinaryColor;
+        use ratatui::style::Stylize;
+        use ratatui::text::Line;
+
+        let mut display = MockDisplay::<BinaryColor>::new();
+        display.set_allow_overdraw(true);
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            dithering: Dithering::Bayer4x4,
+            ..Default::default()
+        };
+        let backend = EmbeddedBackend::new(&mut display, config);
+        let mut terminal = Terminal::new(backend).expect("to create terminal");
+        terminal
+            .draw(|frame| {
+                let gray = ratatui::style::Color::Rgb(128, 128, 128);
+                frame.render_widget(Line::from(" ".bg(gray)), frame.area());
+            })
+            .expect("to draw");
+
+        let display = terminal.backend().display();
+        for y in 0..4 {
+            for x in 0..4 {
+                let expected = if (x + y) % 2 == 0 {
+                    BinaryColor::On
+                } else {
+                    BinaryColor::Off
+                };
+                assert_eq!(display.get_pixel(Point::new(x, y)), Some(expected));
+            }
+        }
+    }
+
+    #[rstest]
+    #[case::truncate(ConversionQuality::Truncate, Rgb565::new(0, 0, 0))]
+    #[case::round(ConversionQuality::Round, Rgb565::new(1, 1, 1))]
+    fn conversion_quality_reduces_channels(
+        #[case] conversion_quality: ConversionQuality,
+        #[case] expected: Rgb565,
+    ) {
+        use ratatui::style::Stylize;
+        use ratatui::text::Line;
+
+        let mut display = MockDisplay::<Rgb565>::new();
+        display.set_allow_overdraw(true);
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            conversion_quality,
+            ..Default::default()
+        };
+        let backend = EmbeddedBackend::new(&mut display, config);
+        let mut terminal = Terminal::new(backend).expect("to create terminal");
+        terminal
+            .draw(|frame| {
+                let color = ratatui::style::Color::Rgb(7, 3, 7);
+                frame.render_widget(Line::from(" ".bg(color)), frame.area());
+            })
+            .expect("to draw");
+
+        let display = terminal.backend().display();
+        assert_eq!(display.get_pixel(Point::new(0, 0)), Some(expected));
+    }
+
+    #[test]
+    fn dither_conversion_mixes_neighbouring_levels() {
+        use ratatui::style::Stylize;
+        use ratatui::text::Line;
+
+        let mut display = MockDisplay::<Rgb565>::new();
+        display.set_allow_overdraw(true);
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            conversion_quality: ConversionQuality::Dither,
+            ..Default::default()
+        };
+        let backend = EmbeddedBackend::new(&mut display, config);
+        let mut terminal = Terminal::new(backend).expect("to create terminal");
+        terminal
+            .draw(|frame| {
+                let color = ratatui::style::Color::Rgb(4, 0, 0);
+                frame.render_widget(Line::from(" ".bg(color)), frame.area());
+            })
+            .expect("to draw");
+
+        // Halfway between the two lowest red levels, half the pixels round up.
+        let display = terminal.backend().display();
+        let lit = (0..4)
+            .flat_map(|y| (0..4).map(move |x| Point::new(x, y)))
+            .filter(|&point| display.get_pixel(point) == Some(Rgb565::new(1, 0, 0)))
+            .count();
+        assert_eq!(lit, 8);
+    }
+
+    #[cfg(feature = "glyph-cache")]
+    #[rstest]
+    fn repeated_glyph_is_drawn_from_cache(mut display0: MockDisplay<Rgb888>) {
+        use ratatui_core::buffer::Cell;
+
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display0, config);
+
+        let mut cell = Cell::new("A");
+        cell.fg = style::Color::White;
+        cell.bg = style::Color::Blue;
+        let mut other = cell.clone();
+        other.bg = style::Color::Red;
+        backend
+            .draw([(0, 0, &cell), (0, 1, &other)].into_iter())
+            .unwrap();
+        assert_eq!(backend.glyph_cache_hits(), 0);
+
+        backend.draw([(1, 0, &cell)].into_iter()).unwrap();
+        backend.flush().unwrap();
+        assert_eq!(backend.glyph_cache_hits(), 1);
+
+        let cell_area = Rectangle::new(Point::zero(), Size::new(4, 6));
+        for point in cell_area.points() {
+            assert_eq!(
+                backend.display().get_pixel(point + Point::new(4, 0)),
+                backend.display().get_pixel(point),
+                "{point:?}"
+            );
+        }
+    }
+
+    #[rstest]
+    fn screensaver_starts_after_timeout(mut display0: MockDisplay<Rgb888>) {
+        use alloc::rc::Rc;
+        use alloc::vec::Vec;
+        use core::cell::RefCell;
+
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let recorded = events.clone();
+        let config = EmbeddedBackendConfig {
+            screensaver_timeout: Some(3),
+            screensaver_callback: Box::new(move |_, active| recorded.borrow_mut().push(active)),
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display0, config);
+
+        backend.flush().unwrap();
+        backend.flush().unwrap();
+        backend.note_activity();
+        backend.flush().unwrap();
+        backend.flush().unwrap();
+        assert!(events.borrow().is_empty());
+
+        backend.flush().unwrap();
+        backend.flush().unwrap();
+        assert!(backend.screensaver_active());
+        assert_eq!(*events.borrow(), [true]);
+
+        backend.note_activity();
+        assert!(!backend.screensaver_active());
+        assert_eq!(*events.borrow(), [true, false]);
+    }
+
+    #[cfg(feature = "frame-limiter")]
+    #[rstest]
+    fn frame_limiter_skips_early_flushes(mut display0: MockDisplay<Rgb888>) {
+        use alloc::rc::Rc;
+        use core::cell::Cell;
+
+        let now = Rc::new(Cell::new(Duration::ZERO));
+        let flushes = Rc::new(Cell::new(0));
+        let (clock, flushed) = (now.clone(), flushes.clone());
+        let config = EmbeddedBackendConfig {
+            flush_callback: Box::new(move |_, _| flushed.set(flushed.get() + 1)),
+            frame_limiter: Some(crate::FrameLimiter::new(20, move || clock.get())),
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display0, config);
+        #[cfg(feature = "blink")]
+        assert_eq!(backend.blink_config.fps, 20);
+
+        backend.flush().unwrap();
+        now.set(Duration::from_millis(30));
+        backend.flush().unwrap();
+        assert_eq!(flushes.get(), 1);
+
+        now.set(Duration::from_millis(50));
+        backend.clear().unwrap();
+        backend.flush().unwrap();
+        assert_eq!(flushes.get(), 2);
+    }
+
+    #[cfg(feature = "frame-limiter")]
+    #[rstest]
+    fn frame_limiter_keeps_skipped_frame_pending(mut display0: MockDisplay<Rgb888>) {
+        use alloc::rc::Rc;
+        use core::cell::Cell;
+
+        let now = Rc::new(Cell::new(Duration::ZERO));
+        let flushes = Rc::new(Cell::new(0));
+        let (clock, flushed) = (now.clone(), flushes.clone());
+        let config = EmbeddedBackendConfig {
+            flush_callback: Box::new(move |_, _| flushed.set(flushed.get() + 1)),
+            frame_limiter: Some(crate::FrameLimiter::new(20, move || clock.get())),
+            screensaver_timeout: Some(2),
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display0, config);
+
+        backend.flush().unwrap();
+        assert!(!backend.frame_pending());
+        backend.clear().unwrap();
+        backend.flush().unwrap();
+        assert!(backend.frame_pending());
+        // Skipped flushes still count towards the screensaver timeout.
+        assert!(backend.screensaver_active());
+
+        backend.flush_pending().unwrap();
+        assert_eq!(flushes.get(), 2);
+        assert!(!backend.frame_pending());
+        backend.flush_pending().unwrap();
+        assert_eq!(flushes.get(), 2);
+    }
+
+    #[cfg(feature = "framebuffer")]
+    #[rstest]
+    fn watchdog_is_fed_during_chunked_flush(mut display0: MockDisplay<Rgb888>) {
+        use alloc::rc::Rc;
+        use core::cell::Cell as StdCell;
+        use ratatui_core::buffer::Cell;
+
+        let now = Rc::new(StdCell::new(Duration::ZERO));
+        let feeds = Rc::new(StdCell::new(0));
+        let (clock, fed) = (now.clone(), feeds.clone());
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            frame_budget: Some(Duration::from_millis(2)),
+            // Every clock reading takes one millisecond.
+            frame_clock: Some(Box::new(move || {
+                clock.set(clock.get() + Duration::from_millis(1));
+                clock.get()
+            })),
+            watchdog_feed_callback: Some(Box::new(move || fed.set(fed.get() + 1))),
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display0, config);
+
+        let mut cell = Cell::new("x");
+        cell.bg = style::Color::Red;
+        let cells: alloc::vec::Vec<_> = (0..10).map(|y| (0, y, cell.clone())).collect();
+        backend
+            .draw(cells.iter().map(|(x, y, cell)| (*x, *y, cell)))
+            .unwrap();
+        assert!(feeds.get() > 0);
+
+        feeds.set(0);
+        backend.flush().unwrap();
+        // Ten character rows are transferred one at a time.
+        assert_eq!(feeds.get(), 5);
+        let red = ColorTheme::default().red;
+        assert_eq!(backend.display().get_pixel(Point::new(0, 59)), Some(red));
+    }
+
+    #[rstest]
+    fn color_remap_replaces_cell_colors(mut display0: MockDisplay<Rgb888>) {
+        use ratatui::style::{Color, Stylize};
+        use ratatui::text::Line;
+
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            color_remap: Some(&[(Color::Blue, Color::LightBlue)]),
+            ..Default::default()
+        };
+        let backend = EmbeddedBackend::new(&mut display0, config);
+        let mut terminal = Terminal::new(backend).expect("to create terminal");
+        terminal
+            .draw(|frame| frame.render_widget(Line::from(" ".on_blue()), frame.area()))
+            .expect("to draw");
+
+        let light_blue = ColorTheme::default().light_blue;
+        let display = terminal.backend().display();
+        assert_eq!(display.get_pixel(Point::new(0, 0)), Some(light_blue));
+    }
+
+    #[rstest]
+    fn circle_clip_skips_corner_cells(mut display0: MockDisplay<Rgb888>) {
+        use ratatui_core::buffer::Cell;
+
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            clip_shape: ClipShape::Circle,
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display0, config);
+        backend.hide_cursor().unwrap();
+        let mut cell = Cell::new(" ");
+        cell.set_bg(ratatui::style::Color::Red);
+        let grid = (0..10).flat_map(|y| (0..16).map(move |x| (x, y)));
+        backend.draw(grid.map(|(x, y)| (x, y, &cell))).unwrap();
+        backend.flush().unwrap();
+
+        let display = backend.display();
+        for corner in [(0, 0), (60, 0), (0, 54), (60, 54)] {
+            let pixel = display.get_pixel(Point::new(corner.0, corner.1));
+            assert_ne!(pixel, Some(Rgb888::RED), "corner {corner:?}");
+        }
+        assert_eq!(display.get_pixel(Point::new(32, 30)), Some(Rgb888::RED));
+        // Cells on the edge are drawn when their center is inside the circle.
+        assert_eq!(display.get_pixel(Point::new(0, 30)), Some(Rgb888::RED));
+    }
+
+    #[rstest]
+    fn max_cells_per_draw_limits_draws(mut display0: MockDisplay<Rgb888>) {
+        let config = EmbeddedBackendConfig {
+            max_cells_per_draw: Some(4),
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display0, config);
+        let cell = ratatui_core::buffer::Cell::new("x");
+
+        let cells = (0..4).map(|x| (x, 0, &cell));
+        assert!(backend.draw(cells).is_ok());
+        let cells = (0..5).map(|x| (x, 0, &cell));
+        assert!(matches!(
+            backend.draw(cells),
+            Err(crate::error::Error::CellLimit(4))
+        ));
+    }
+
+    #[rstest]
+    fn watermark_is_drawn_over_content_every_frame(mut display0: MockDisplay<Rgb888>) {
+        use ratatui::style::Stylize;
+        use ratatui::text::Line;
+
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display0, config);
+        // The '|' glyph of FONT_4X6 has a pixel at (1, 1).
+        backend.set_watermark("|", Point::zero(), Rgb888::GREEN);
+        let mut terminal = Terminal::new(backend).expect("to create terminal");
+
+        for bg in [ratatui::style::Color::Red, ratatui::style::Color::Blue] {
+            terminal
+                .draw(|frame| frame.render_widget(Line::from("    ".bg(bg)), frame.area()))
+                .expect("to draw");
+            let display = terminal.backend().display();
+            assert_eq!(display.get_pixel(Point::new(1, 1)), Some(Rgb888::GREEN));
+        }
+    }
+
+    #[cfg(feature = "framebuffer")]
+    #[rstest]
+    fn scrolled_content_leaves_no_watermark_behind(mut display0: MockDisplay<Rgb888>) {
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display0, config);
+        // The '|' glyph of FONT_4X6 has a pixel at (1, 1), so this one at (1, 7).
+        backend.set_watermark("|", Point::new(0, 6), Rgb888::GREEN);
+        let cell = ratatui_core::buffer::Cell::new(" ");
+        backend.draw([(0, 1, &cell)].into_iter()).unwrap();
+        backend.flush().unwrap();
+
+        backend.scroll_up(1).unwrap();
+        backend.flush().unwrap();
+
+        let display = backend.display();
+        assert_eq!(display.get_pixel(Point::new(1, 1)), Some(Rgb888::BLACK));
+        assert_eq!(display.get_pixel(Point::new(1, 7)), Some(Rgb888::GREEN));
+    }
+
+    #[cfg(feature = "framebuffer")]
+    #[rstest]
+    fn cleared_watermark_is_removed_from_unchanged_content(mut display0: MockDisplay<Rgb888>) {
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display0, config);
+        backend.set_watermark("|", Point::new(0, 6), Rgb888::GREEN);
+        let cell = ratatui_core::buffer::Cell::new(" ");
+        backend.draw([(0, 1, &cell)].into_iter()).unwrap();
+        backend.flush().unwrap();
+        assert_eq!(
+            backend.display().get_pixel(Point::new(1, 7)),
+            Some(Rgb888::GREEN)
+        );
+
+        backend.clear_watermark();
+        backend.draw([(0, 1, &cell)].into_iter()).unwrap();
+        backend.flush().unwrap();
+
+        assert_eq!(
+            backend.display().get_pixel(Point::new(1, 7)),
+            Some(Rgb888::BLACK)
+        );
+    }
+
+    #[test]
+    fn single_cell_grid() {
+        use ratatui::style::Stylize;
+        use ratatui::text::Line;
+
+        let mut mock = MockDisplay::<Rgb888>::new();
+        let mut display = TestDisplay::new(&mut mock);
+        // Exactly one `FONT_4X6` cell in size.
+        display.area = Rectangle::new(Point::zero(), geometry::Size::new(4, 6));
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            cursor: CursorConfig {
+                style: crate::CursorStyle::Underline,
+                blink: false,
+                color: Rgb888::GREEN,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let backend = EmbeddedBackend::new(&mut display, config);
+        let mut terminal = Terminal::new(backend).expect("to create terminal");
+        assert_eq!(terminal.size().unwrap(), layout::Size::new(1, 1));
+        terminal
+            .draw(|frame| {
+                frame.render_widget(Line::from("x".on_red()), frame.area());
+                frame.set_cursor_position((1, 1));
+            })
+            .expect("to draw");
+
+        let display = &terminal.backend().display().display;
+        // The cursor is clamped to the only cell.
+        assert_eq!(display.get_pixel(Point::new(0, 0)), Some(Rgb888::RED));
+        assert_eq!(display.get_pixel(Point::new(3, 5)), Some(Rgb888::GREEN));
+        assert_eq!(display.get_pixel(Point::new(4, 0)), None);
+        assert_eq!(display.get_pixel(Point::new(0, 6)), None);
+        // Only the drawn cursor is clamped, not the position the app set.
+        assert_eq!(
+            terminal.backend_mut().get_cursor_position().unwrap(),
+            layout::Position::new(1, 1)
+        );
+    }
+
+    #[cfg(feature = "framebuffer")]
+    #[test]
+    fn content_is_offset_by_display_origin() {
+        use ratatui::style::Stylize;
+        use ratatui::text::Line;
+
+        let mut mock = MockDisplay::<Rgb888>::new();
+        let mut display = TestDisplay::new(&mut mock);
+        // Sub-display of the mock display starting at (10, 10).
+        display.area = Rectangle::new(Point::new(10, 10), geometry::Size::new(54, 54));
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            ..Default::default()
+        };
+        let backend = EmbeddedBackend::new(&mut display, config);
+        let mut terminal = Terminal::new(backend).expect("to create terminal");
+        terminal
+            .draw(|frame| frame.render_widget(Line::from(" ".on_red()), frame.area()))
+            .expect("to draw");
+
+        let display = &terminal.backend().display().display;
+        assert_eq!(display.get_pixel(Point::new(9, 9)), None);
+        assert_eq!(display.get_pixel(Point::new(10, 10)), Some(Rgb888::RED));
+        assert_eq!(display.get_pixel(Point::new(13, 15)), Some(Rgb888::RED));
+        assert_eq!(display.get_pixel(Point::new(14, 16)), Some(Rgb888::BLACK));
+    }
+
+    #[cfg(feature = "blink")]
+    #[rstest]
+    fn dim_blink_style_dims_hidden_phase(mut display0: MockDisplay<Rgb888>) {
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            native_block_elements: true,
+            blink: BlinkConfig {
+                style: BlinkStyle::Dim,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display0, config);
+        // The default slow blink hides during the last 5 frames of each 30 frame cycle.
+        for _ in 0..26 {
+            backend.draw(core::iter::empty()).unwrap();
+        }
+        let mut cell = ratatui_core::buffer::Cell::new("█");
+        cell.modifier = style::Modifier::SLOW_BLINK;
+        backend.draw(core::iter::once((0, 0, &cell))).unwrap();
+        assert!(backend.blink_config.slow.is_hidden());
+        backend.flush().unwrap();
+
+        assert_eq!(
+            backend.display().get_pixel(Point::new(0, 0)),
+            Some(dim_color(Rgb888::WHITE))
+        );
+    }
+
+    #[cfg(feature = "framebuffer")]
+    #[rstest]
+    fn framebuffer_bytes_matches_display_size(mut display0: MockDisplay<Rgb888>) {
+        let backend = EmbeddedBackend::new(&mut display0, EmbeddedBackendConfig::default());
+        assert_eq!(
+            backend.framebuffer_bytes(),
+            64 * 64 * core::mem::size_of::<Rgb888>()
+        );
+    }
+
+    #[rstest]
+    fn set_color_theme_repaints_with_new_colors(mut display0: MockDisplay<Rgb888>) {
+        use ratatui::style::Stylize;
+        use ratatui::text::Line;
+
+        let backend = EmbeddedBackend::new(&mut display0, EmbeddedBackendConfig::default());
+        let mut terminal = Terminal::new(backend).expect("to create terminal");
+        let render = |frame: &mut ratatui::Frame| {
+            frame.render_widget(Line::from(" ".on_red()), frame.area());
+        };
+        terminal.draw(render).expect("to draw");
+        assert_eq!(
+            terminal.backend().display().get_pixel(Point::new(0, 0)),
+            Some(Rgb888::RED)
+        );
+
+        let theme = ColorTheme::tokyo_night();
+        terminal.backend_mut().set_color_theme(theme);
+        terminal.clear().expect("to clear");
+        terminal.draw(render).expect("to draw");
+        let display = terminal.backend().display();
+        assert_eq!(display.get_pixel(Point::new(0, 0)), Some(theme.red));
+        assert_eq!(display.get_pixel(Point::new(0, 20)), Some(theme.background));
+    }
+
+    #[cfg(feature = "framebuffer")]
+    #[rstest]
+    #[case(0, false)]
+    #[case(1, true)]
+    fn flush_retries_failed_transfer(#[case] flush_retries: u8, #[case] transferred: bool) {
+        use alloc::rc::Rc;
+        use core::cell::Cell;
+
+        let retries = Rc::new(Cell::new(0));
+        let mut mock = MockDisplay::<Rgb888>::new();
+        let mut display = TestDisplay::new(&mut mock);
+        // The first transfer fails.
+        display.failures = 1;
+        let config = EmbeddedBackendConfig {
+            flush_retries,
+            flush_retry_delay: Some(Box::new({
+                let retries = retries.clone();
+                move |retry| retries.set(retry)
+            })),
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display, config);
+
+        assert_eq!(backend.flush().is_ok(), transferred);
+        assert_eq!(retries.get(), flush_retries);
+        let pixel = backend.display().display.get_pixel(Point::zero());
+        assert_eq!(pixel.is_some(), transferred);
+    }
+
+    #[test]
+    fn draw_error_keeps_display_error() {
+        let mut mock = MockDisplay::<Rgb888>::new();
+        let mut display = TestDisplay::new(&mut mock);
+        // The bus always times out.
+        display.failures = usize::MAX;
+        let mut backend = EmbeddedBackend::new(&mut display, EmbeddedBackendConfig::default());
+        let cell = ratatui_core::buffer::Cell::new("x");
+        let error = backend
+            .draw([(0, 0, &cell)].into_iter())
+            .and_then(|()| backend.flush())
+            .unwrap_err();
+
+        assert!(matches!(error, crate::error::Error::DrawError(_)));
+        assert_eq!(
+            alloc::format!("{error}"),
+            "drawing to DrawTarget failed: Timeout"
+        );
+
+        // Errors can be moved into `Box<dyn Error + Send + Sync>` and `anyhow`.
+        fn assert_send_sync<T: Send + Sync>(_: &T) {}
+        assert_send_sync(&error);
+    }
+
+    #[cfg(feature = "framebuffer")]
+    #[rstest]
+    #[case::plain(style::Modifier::empty(), [Rgb888::WHITE, Rgb888::WHITE])]
+    #[case::reversed(style::Modifier::REVERSED, [Rgb888::WHITE, Rgb888::BLACK])]
+    fn inverse_cursor_outlines_reversed_cells(
+        mut display0: MockDisplay<Rgb888>,
+        #[case] modifier: style::Modifier,
+        #[case] expected: [Rgb888; 2],
+    ) {
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            cursor: CursorConfig {
+                blink: false,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display0, config);
+        let mut cell = ratatui_core::buffer::Cell::new(" ");
+        cell.modifier = modifier;
+        backend.draw([(1, 1, &cell)].into_iter()).unwrap();
+        backend.set_cursor_position((1, 1)).unwrap();
+        backend.show_cursor().unwrap();
+        backend.flush().unwrap();
+
+        // A reversed blank cell is white: the cursor keeps its inside and only
+        // inverts its outline, so it stands out from reversed cells around it.
+        let display = backend.display();
+        assert_eq!(display.get_pixel(Point::new(5, 8)), Some(expected[0]));
+        assert_eq!(display.get_pixel(Point::new(4, 6)), Some(expected[1]));
+    }
+
+    #[test]
+    fn monochrome_modifiers() {
+        let mut display = MockDisplay::<BinaryColor>::new();
+        display.set_allow_overdraw(true);
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            native_block_elements: true,
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display, config);
+        let mut reversed = ratatui_core::buffer::Cell::new("-");
+        reversed.modifier = style::Modifier::REVERSED;
+        let mut dimmed = ratatui_core::buffer::Cell::new("█");
+        dimmed.modifier = style::Modifier::DIM;
+        backend
+            .draw([(0, 0, &reversed), (1, 0, &dimmed)].into_iter())
+            .unwrap();
+        backend.flush().unwrap();
+
+        let display = backend.display();
+        // Reversed text is drawn off on on.
+        assert_eq!(display.get_pixel(Point::new(0, 0)), Some(BinaryColor::On));
+        assert_eq!(display.get_pixel(Point::new(1, 2)), Some(BinaryColor::Off));
+        // Dimmed text stays visible.
+        assert_eq!(display.get_pixel(Point::new(4, 0)), Some(BinaryColor::On));
+    }
+
+    #[test]
+    fn compact_config_suits_monochrome_displays() {
+        let mut display = MockDisplay::<BinaryColor>::new();
+        display.set_allow_overdraw(true);
+        let mut backend = EmbeddedBackend::new(&mut display, EmbeddedBackendConfig::compact());
+        assert_eq!(backend.size().unwrap(), layout::Size::new(12, 8));
+
+        let mut cell = ratatui_core::buffer::Cell::new("H");
+        cell.fg = style::Color::Red;
+        backend.draw([(0, 0, &cell)].into_iter()).unwrap();
+        backend.flush().unwrap();
+
+        let display = backend.display();
+        // Colored text stays visible instead of collapsing to the background.
+        assert_eq!(display.get_pixel(Point::new(0, 1)), Some(BinaryColor::On));
+        assert_eq!(display.get_pixel(Point::new(1, 1)), Some(BinaryColor::Off));
+    }
+
+    #[test]
+    fn epaper_profile_keeps_meaningful_modifiers() {
+        let mut display = MockDisplay::<BinaryColor>::new();
+        display.set_allow_overdraw(true);
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            modifier_profile: ModifierProfile::EPaper,
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display, config);
+        let modifiers = [
+            style::Modifier::empty(),
+            style::Modifier::BOLD,
+            style::Modifier::REVERSED,
+            style::Modifier::DIM,
+        ];
+        let cells = modifiers.map(|modifier| {
+            let mut cell = ratatui_core::buffer::Cell::new("l");
+            cell.modifier = modifier;
+            cell
+        });
+        backend
+            .draw((0..).zip(&cells).map(|(x, cell)| (x, 0, cell)))
+            .unwrap();
+        backend.flush().unwrap();
+
+        let display = backend.display();
+        let cell_pixels = |x: i32| -> alloc::vec::Vec<_> {
+            Rectangle::new(Point::new(x * 4, 0), Size::new(4, 6))
+                .points()
+                .map(|point| display.get_pixel(point))
+                .collect()
+        };
+        let plain = cell_pixels(0);
+        assert_ne!(cell_pixels(1), plain, "bold");
+        assert_ne!(cell_pixels(2), plain, "reversed");
+        assert_eq!(cell_pixels(3), plain, "dim");
+    }
+
+    #[cfg(feature = "stats")]
+    #[rstest]
+    fn last_frame_stats_counts_rendering_work(mut display0: MockDisplay<Rgb888>) {
+        use ratatui_core::buffer::Cell;
+
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            clip_shape: ClipShape::Circle,
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display0, config);
+        let text = Cell::new("a");
+        let mut blank = Cell::new(" ");
+        blank.bg = style::Color::Red;
+        backend
+            .draw(
+                [
+                    (6, 5, &text),
+                    (7, 5, &text),
+                    (8, 5, &blank),
+                    (9, 5, &blank),
+                    // Outside the visible circle.
+                    (0, 0, &text),
+                ]
+                .into_iter(),
+            )
+            .unwrap();
+        backend.flush().unwrap();
+
+        assert_eq!(
+            backend.last_frame_stats(),
+            crate::RenderStats {
+                cells_drawn: 4,
+                cells_skipped: 1,
+                fill_solid_calls: 1,
+                glyph_draws: 2,
+            }
+        );
+    }
+
+    /// Gamma 2 lookup table.
+    static SQUARE_LUT: [u8; 256] = {
+        let mut lut = [0; 256];
+        let mut i = 0;
+        while i < 256 {
+            lut[i] = (i * i / 255) as u8;
+            i += 1;
+        }
+        lut
+    };
+
+    #[rstest]
+    #[case::uncorrected(None)]
+    #[case::corrected(Some(&SQUARE_LUT))]
+    fn gamma_lut_corrects_cell_colors(
+        mut display0: MockDisplay<Rgb888>,
+        #[case] gamma_lut: Option<&'static [u8; 256]>,
+    ) {
+        use ratatui::style::Stylize;
+        use ratatui::text::Line;
+
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            gamma_lut,
+            ..Default::default()
+        };
+        let backend = EmbeddedBackend::new(&mut display0, config);
+        let mut terminal = Terminal::new(backend).expect("to create terminal");
+        let ramp = [0, 64, 128, 192, 255];
+        terminal
+            .draw(|frame| {
+                let spans = ramp.map(|v| " ".bg(ratatui::style::Color::Rgb(v, v, v)));
+                frame.render_widget(Line::from_iter(spans), frame.area());
+            })
+            .expect("to draw");
+
+        let display = terminal.backend().display();
+        for (i, v) in ramp.into_iter().enumerate() {
+            let expected = gamma_lut.map_or(v, |lut| lut[v as usize]);
+            assert_eq!(
+                display.get_pixel(Point::new(i as i32 * 4, 0)),
+                Some(Rgb888::new(expected, expected, expected))
+            );
+        }
+    }
+
+    #[test]
+    fn gamma_lut_keeps_binary_conversion() {
+        use embedded_graphics::pixelcolor::BinaryColor;
+
+        let mut display = MockDisplay::<BinaryColor>::new();
+        let config = EmbeddedBackendConfig {
+            gamma_lut: Some(&SQUARE_LUT),
+            ..Default::default()
+        };
+        let backend = EmbeddedBackend::new(&mut display, config);
+
+        // Dark colors stay visible as foreground and bright ones stay dark as
+        // background, like without the lookup table.
+        let dark = style::Color::Rgb(96, 0, 0);
+        let bright = style::Color::Rgb(255, 224, 224);
+        assert_eq!(
+            backend.cell_color(dark, TermColorType::Foreground).0,
+            BinaryColor::On
+        );
+        assert_eq!(
+            backend.cell_color(bright, TermColorType::Background).0,
+            BinaryColor::Off
+        );
+        assert_eq!(
+            backend
+                .cell_color(style::Color::Reset, TermColorType::Background)
+                .0,
+            BinaryColor::Off
+        );
+    }
+
+    #[rstest]
+    fn quantize_snaps_to_theme_colors(mut display0: MockDisplay<Rgb888>) {
+        use ratatui::style::Stylize;
+        use ratatui::text::Line;
+
+        let theme = ColorTheme::tokyo_night();
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            color_theme: theme,
+            quantize: true,
+            ..Default::default()
+        };
+        let backend = EmbeddedBackend::new(&mut display0, config);
+        let mut terminal = Terminal::new(backend).expect("to create terminal");
+        terminal
+            .draw(|frame| {
+                let near_red = ratatui::style::Color::Rgb(0xf0, 0x70, 0x80);
+                frame.render_widget(Line::from(" ".bg(near_red)), frame.area());
+            })
+            .expect("to draw");
+
+        let display = terminal.backend().display();
+        assert_eq!(display.get_pixel(Point::new(0, 0)), Some(theme.red));
+    }
+
+    #[cfg(feature = "framebuffer")]
+    #[test]
+    fn double_buffer_skips_unchanged_frames() {
+        use ratatui_core::buffer::Cell;
+
+        let mut mock = MockDisplay::<Rgb888>::new();
+        let mut display = TestDisplay::new(&mut mock);
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            double_buffer: true,
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display, config);
+        assert_eq!(
+            backend.framebuffer_bytes(),
+            2 * 64 * 64 * core::mem::size_of::<Rgb888>()
+        );
+        backend.hide_cursor().unwrap();
+        let mut cell = Cell::new("x");
+        cell.set_bg(ratatui::style::Color::Red);
+
+        backend.draw([(0, 0, &cell)].into_iter()).unwrap();
+        backend.flush().unwrap();
+        assert_eq!(backend.display().fills.len(), 1);
+
+        backend.display_mut().fills.clear();
+        backend.display_mut().pixels = 0;
+        backend.draw([(0, 0, &cell)].into_iter()).unwrap();
+        backend.flush().unwrap();
+        assert!(backend.display().fills.is_empty());
+        assert_eq!(backend.display().pixels, 0);
+
+        cell.set_bg(ratatui::style::Color::Blue);
+        backend.draw([(0, 0, &cell)].into_iter()).unwrap();
+        backend.flush().unwrap();
+        assert_eq!(
+            backend.display().fills,
+            [Rectangle::new(Point::zero(), Size::new(4, 6))]
+        );
+    }
+
+    #[cfg(feature = "framebuffer")]
+    #[test]
+    fn double_buffer_keeps_flushed_rows_until_next_flush() {
+        use alloc::rc::Rc;
+        use core::cell::RefCell;
+        use ratatui_core::buffer::Cell;
+
+        let mut mock = MockDisplay::<Rgb888>::new();
+        let mut display = TestDisplay::new(&mut mock);
+        let flushed = Rc::new(RefCell::new(alloc::vec::Vec::new()));
+        let row_flush_callback = {
+            let flushed = flushed.clone();
+            Box::new(
+                move |_: &mut TestDisplay<Rgb888>, row: Rectangle, pixels: &[Rgb888]| {
+                    if row.top_left.y == 0 {
+                        flushed.borrow_mut().push((pixels.as_ptr(), pixels[0]));
+                    }
+                    Ok(())
+                },
+            ) as RowFlushFn<TestDisplay<Rgb888>, Rgb888>
+        };
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            double_buffer: true,
+            row_flush_callback: Some(row_flush_callback),
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display, config);
+        backend.hide_cursor().unwrap();
+        let mut cell = Cell::new(" ");
+        cell.set_bg(ratatui::style::Color::Red);
+        backend.draw([(0, 0, &cell)].into_iter()).unwrap();
+        backend.flush().unwrap();
+        cell.set_bg(ratatui::style::Color::Blue);
+        backend.draw([(0, 0, &cell)].into_iter()).unwrap();
+
+        // The next frame is drawn into the other buffer, leaving the flushed one as is.
+        let (front, red) = flushed.borrow()[0];
+        assert_eq!(red, ColorTheme::default().red);
+        assert_eq!(
+            backend.buffer.get_pixel(Point::zero()),
+            Some(ColorTheme::default().blue)
+        );
+
+        backend.flush().unwrap();
+        let (back, blue) = flushed.borrow()[1];
+        assert_eq!(blue, ColorTheme::default().blue);
+        assert_ne!(front, back);
+    }
+
+    #[cfg(feature = "framebuffer")]
+    #[test]
+    fn hw_scroll_transfers_only_exposed_rows() {
+        let mut mock = MockDisplay::<Rgb888>::new();
+        let mut display = TestDisplay::new(&mut mock);
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            capabilities: DisplayCapabilities {
+                hw_scroll: Some(Box::new(|_: &mut TestDisplay, _| {})),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display, config);
+        backend.scroll_up(1).unwrap();
+
+        // 10 rows of 6 pixels, the last one is exposed.
+        assert_eq!(
+            backend.display().fills,
+            [Rectangle::new(
+                Point::new(0, 54),
+                geometry::Size::new(64, 6)
+            )]
+        );
+    }
+
+    #[cfg(feature = "framebuffer")]
+    #[test]
+    fn hw_scroll_leaves_no_cursor_behind() {
+        let mut mock = MockDisplay::<Rgb888>::new();
+        let mut display = TestDisplay::new(&mut mock);
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            capabilities: DisplayCapabilities {
+                hw_scroll: Some(Box::new(|_: &mut TestDisplay, _| {})),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display, config);
+        backend.show_cursor().unwrap();
+        backend.set_cursor_position((1, 2)).unwrap();
+        backend.flush().unwrap();
+        backend.scroll_up(1).unwrap();
+        backend.display_mut().fills.clear();
+        backend.flush().unwrap();
+
+        // The display moved the cursor drawn at row 2 up to row 1.
+        let ghost = Rectangle::new(Point::new(4, 6), geometry::Size::new(4, 6));
+        assert!(
+            backend
+                .display()
+                .fills
+                .iter()
+                .any(|fill| fill.intersection(&ghost) == ghost)
+        );
+    }
+
+    #[rstest]
+    fn clear_current_line_resets_cursor_row(mut display0: MockDisplay<Rgb888>) {
+        use ratatui_core::buffer::Cell;
+
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display0, config);
+        backend.hide_cursor().unwrap();
+        let mut cell = Cell::new(" ");
+        cell.set_bg(ratatui::style::Color::Red);
+        backend
+            .draw([(0, 0, &cell), (15, 1, &cell)].into_iter())
+            .unwrap();
+        backend.set_cursor_position((3, 1)).unwrap();
+        backend.clear_region(ClearType::CurrentLine).unwrap();
+        backend.flush().unwrap();
+
+        let display = backend.display();
+        assert_eq!(display.get_pixel(Point::new(0, 0)), Some(Rgb888::RED));
+        assert_eq!(display.get_pixel(Point::new(3, 5)), Some(Rgb888::RED));
+        assert_eq!(display.get_pixel(Point::new(60, 6)), Some(Rgb888::BLACK));
+        assert_eq!(display.get_pixel(Point::new(63, 11)), Some(Rgb888::BLACK));
+    }
+
+    #[rstest]
+    #[case::after_cursor(ClearType::AfterCursor, [true, true, false, false, false])]
+    #[case::before_cursor(ClearType::BeforeCursor, [false, false, false, true, true])]
+    fn clear_relative_to_cursor(
+        mut display0: MockDisplay<Rgb888>,
+        #[case] clear_type: ClearType,
+        #[case] kept: [bool; 5],
+    ) {
+        use ratatui_core::buffer::Cell;
+
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display0, config);
+        backend.hide_cursor().unwrap();
+        let mut cell = Cell::new(" ");
+        cell.set_bg(ratatui::style::Color::Red);
+        // Cells on the row above the cursor at (8, 5), before, at and after it, and
+        // on the row below.
+        let cells = [(0, 4), (7, 5), (8, 5), (9, 5), (0, 6)];
+        backend
+            .draw(cells.iter().map(|&(x, y)| (x, y, &cell)))
+            .unwrap();
+        backend.set_cursor_position((8, 5)).unwrap();
+        backend.clear_region(clear_type).unwrap();
+        backend.flush().unwrap();
+
+        let display = backend.display();
+        let color_at =
+            |(x, y): (u16, u16)| display.get_pixel(Point::new(x as i32 * 4, y as i32 * 6));
+        for (&position, kept) in cells.iter().zip(kept) {
+            let expected = if kept { Rgb888::RED } else { Rgb888::BLACK };
+            assert_eq!(color_at(position), Some(expected), "cell {position:?}");
+        }
+    }
+
+    #[rstest]
+    fn clear_until_new_line_resets_rest_of_row(mut display0: MockDisplay<Rgb888>) {
+        use ratatui_core::buffer::Cell;
+
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display0, config);
+        backend.hide_cursor().unwrap();
+        let mut cell = Cell::new(" ");
+        cell.set_bg(ratatui::style::Color::Red);
+        let cells = [(4, 2), (5, 2), (15, 2), (0, 3)];
+        backend
+            .draw(cells.iter().map(|&(x, y)| (x, y, &cell)))
+            .unwrap();
+        backend.set_cursor_position((5, 2)).unwrap();
+        backend.clear_region(ClearType::UntilNewLine).unwrap();
+        backend.flush().unwrap();
+
+        let display = backend.display();
+        for ((x, y), kept) in cells.into_iter().zip([true, false, false, true]) {
+            let expected = if kept { Rgb888::RED } else { Rgb888::BLACK };
+            let pixel = display.get_pixel(Point::new(x as i32 * 4, y as i32 * 6));
+            assert_eq!(pixel, Some(expected), "cell ({x}, {y})");
+        }
+    }
+
+    #[rstest]
+    fn columns_start_at_their_column(mut display0: MockDisplay<Rgb888>) {
+        use ratatui::layout::Rect;
+
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display0, config);
+        let style = style::Style::default();
+        backend
+            .draw_text_ellipsis(Rect::new(0, 1, 16, 1), "xxxxxxxxxxxxxxxx", style)
+            .unwrap();
+        backend
+            .draw_columns(
+                1,
+                &[(0, "Name", style), (9, "Qty", style), (5, "Price", style)],
+            )
+            .unwrap();
+        backend.flush().unwrap();
+
+        let mut reference = MockDisplay::<Rgb888>::new();
+        reference.set_allow_overdraw(true);
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            ..Default::default()
+        };
+        let mut backend_ref = EmbeddedBackend::new(&mut reference, config);
+        for (x, ch) in (0..).zip("Name PricQty    ".chars()) {
+            let mut cell = ratatui_core::buffer::Cell::EMPTY;
+            cell.set_char(ch);
+            backend_ref.draw_cell(x, 1, &cell).unwrap();
+        }
+        backend_ref.flush().unwrap();
+
+        assert_eq!(backend.display(), backend_ref.display());
+    }
+
+    #[rstest]
+    #[case::fits("Hi", "Hi")]
+    #[case::truncated("Hello, world", "Hel..")]
+    fn text_ellipsis_truncates_to_rect(
+        mut display0: MockDisplay<Rgb888>,
+        #[case] text: &str,
+        #[case] expected: &str,
+    ) {
+        use ratatui::layout::Rect;
+
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display0, config);
+        backend
+            .draw_text_ellipsis(Rect::new(0, 0, 5, 1), text, style::Style::default())
+            .unwrap();
+        backend.flush().unwrap();
+
+        let mut reference = MockDisplay::<Rgb888>::new();
+        reference.set_allow_overdraw(true);
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            ..Default::default()
+        };
+        let mut backend_ref = EmbeddedBackend::new(&mut reference, config);
+        for (x, ch) in (0..).zip(expected.chars()) {
+            let mut cell = ratatui_core::buffer::Cell::EMPTY;
+            cell.set_char(ch);
+            backend_ref.draw_cell(x, 0, &cell).unwrap();
+        }
+        backend_ref.flush().unwrap();
+
+        assert_eq!(backend.display(), backend_ref.display());
+    }
+
+    #[rstest]
+    #[case::bold_italic_font(
+        Some(embedded_graphics::mono_font::ascii::FONT_7X13),
+        embedded_graphics::mono_font::ascii::FONT_7X13
+    )]
+    #[case::bold_fallback(None, embedded_graphics::mono_font::ascii::FONT_5X8)]
+    fn bold_italic_cells_use_combined_font(
+        mut display0: MockDisplay<Rgb888>,
+        #[case] font_bold_italic: Option<MonoFont<'static>>,
+        #[case] expected: MonoFont<'static>,
+    ) {
+        use embedded_graphics::mono_font::ascii;
+        use ratatui_core::buffer::Cell;
+
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            font_bold: Some(ascii::FONT_5X8),
+            font_italic: Some(ascii::FONT_6X9),
+            font_bold_italic,
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display0, config);
+        let mut cell = Cell::new("A");
+        cell.modifier = style::Modifier::BOLD | style::Modifier::ITALIC;
+        backend.draw_cell(0, 0, &cell).unwrap();
+        backend.flush().unwrap();
+
+        let mut reference = MockDisplay::<Rgb888>::new();
+        reference.set_allow_overdraw(true);
+        let config = EmbeddedBackendConfig {
+            font_regular: expected,
+            ..Default::default()
+        };
+        let mut backend_ref = EmbeddedBackend::new(&mut reference, config);
+        backend_ref.draw_cell(0, 0, &Cell::new("A")).unwrap();
+        backend_ref.flush().unwrap();
+
+        assert_eq!(backend.display(), backend_ref.display());
+    }
+
+    #[cfg(feature = "fonts")]
+    #[rstest]
+    fn missing_glyphs_use_fallback_font(mut display0: MockDisplay<Rgb888>) {
+        use embedded_graphics_unicodefonts::MONO_4X6;
+        use ratatui_core::buffer::Cell;
+
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            fallback_font: Some(MONO_4X6),
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display0, config);
+        assert!(backend.can_render('┼'));
+        backend.draw_cell(0, 0, &Cell::new("┼")).unwrap();
+        backend.flush().unwrap();
+
+        let mut reference = MockDisplay::<Rgb888>::new();
+        reference.set_allow_overdraw(true);
+        let config = EmbeddedBackendConfig {
+            font_regular: MONO_4X6,
+            ..Default::default()
+        };
+        let mut backend_ref = EmbeddedBackend::new(&mut reference, config);
+        backend_ref.draw_cell(0, 0, &Cell::new("┼")).unwrap();
+        backend_ref.flush().unwrap();
+
+        assert_eq!(backend.display(), backend_ref.display());
+    }
+
+    #[rstest]
+    #[case::ascii('a', false, true)]
+    #[case::replacement_glyph('?', false, true)]
+    #[case::uncovered('€', false, false)]
+    #[case::block_element('█', false, false)]
+    #[case::native_block_element('█', true, true)]
+    fn can_render_checks_font_coverage(
+        mut display0: MockDisplay<Rgb888>,
+        #[case] ch: char,
+        #[case] native_block_elements: bool,
+        #[case] expected: bool,
+    ) {
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            native_block_elements,
+            ..Default::default()
+        };
+        let backend = EmbeddedBackend::new(&mut display0, config);
+        assert_eq!(backend.can_render(ch), expected);
+    }
+
+    #[cfg(feature = "framebuffer")]
+    #[test]
+    fn flush_transfers_only_dirty_region() {
+        let mut mock = MockDisplay::<Rgb888>::new();
+        let mut display = TestDisplay::new(&mut mock);
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display, config);
+        backend.flush().unwrap();
+        assert_eq!(
+            backend.display_mut().fills.split_off(0),
+            [Rectangle::new(Point::zero(), geometry::Size::new(64, 64))]
+        );
+
+        let cell = ratatui_core::buffer::Cell::new("x");
+        backend.draw(core::iter::once((2, 1, &cell))).unwrap();
+        backend.flush().unwrap();
+        assert_eq!(
+            backend.display().fills,
+            [Rectangle::new(Point::new(8, 6), geometry::Size::new(4, 6))]
+        );
+
+        backend.flush().unwrap();
+        assert_eq!(backend.display().fills.len(), 1);
+    }
+
+    #[cfg(any(feature = "cell-cache", feature = "framebuffer"))]
+    #[test]
+    fn unchanged_cells_are_not_redrawn() {
+        let mut mock = MockDisplay::<Rgb888>::new();
+        let mut display = TestDisplay::new(&mut mock);
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display, config);
+        let cells = [
+            ratatui_core::buffer::Cell::new("a"),
+            ratatui_core::buffer::Cell::new("b"),
+        ];
+        let frame = || (0..).zip(&cells).map(|(x, cell)| (x, 0, cell));
+
+        backend.draw(frame()).unwrap();
+        backend.flush().unwrap();
+        assert!(backend.display().pixels > 0);
+
+        backend.display_mut().pixels = 0;
+        backend.draw(frame()).unwrap();
+        backend.flush().unwrap();
+        assert_eq!(backend.display().pixels, 0);
+    }
+
+    #[test]
+    fn double_height_spans_two_rows() {
+        use ratatui::layout::Rect;
+
+        let render = |double: bool| {
+            let mut display = MockDisplay::<Rgb888>::new();
+            display.set_allow_overdraw(true);
+            let config = EmbeddedBackendConfig {
+                font_regular: FONT_4X6,
+                ..Default::default()
+            };
+            let mut backend = EmbeddedBackend::new(&mut display, config);
+            let style = style::Style::default();
+            if double {
+                backend.draw_double_height(0, "Hi", style).unwrap();
+            } else {
+                backend
+                    .draw_text_ellipsis(Rect::new(0, 0, 2, 1), "Hi", style)
+                    .unwrap();
+            }
+            backend.flush().unwrap();
+            display
+        };
+        let single = render(false);
+        let double = render(true);
+
+        for y in 0..6 {
+            for x in 0..8 {
+                let pixel = single.get_pixel(Point::new(x, y));
+                assert!(pixel.is_some());
+                assert_eq!(double.get_pixel(Point::new(x, 2 * y)), pixel);
+                assert_eq!(double.get_pixel(Point::new(x, 2 * y + 1)), pixel);
+            }
+        }
+    }
+
+    #[rstest]
+    #[case::in_cell_decorations(embedded_graphics::mono_font::ascii::FONT_6X10, false)]
+    #[case::clipped_overflow(FONT_4X6, true)]
+    fn clear_widget_removes_decorations(
+        display0: MockDisplay<Rgb888>,
+        #[case] font_regular: MonoFont<'static>,
+        #[case] clip_to_cell: bool,
+    ) {
+        assert_clear_removes_decorations(display0, font_regular, clip_to_cell);
+    }
+
+    // FONT_4X6 draws its underline into the row below, which is restored from the
+    // framebuffer or the cell cache.
+    #[cfg(any(feature = "framebuffer", feature = "cell-cache"))]
+    #[rstest]
+    fn clear_widget_removes_overflowing_underline(display0: MockDisplay<Rgb888>) {
+        assert_clear_removes_decorations(display0, FONT_4X6, false);
+    }
+
+    #[cfg(feature = "framebuffer")]
+    #[rstest]
+    fn scrolled_underline_overflow_is_restored(mut display0: MockDisplay<Rgb888>) {
+        use ratatui_core::buffer::Cell;
+
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display0, config);
+        let mut underlined = Cell::new("_");
+        underlined.modifier = style::Modifier::UNDERLINED;
+        backend.draw([(0, 1, &underlined)].into_iter()).unwrap();
+        backend.scroll_up(1).unwrap();
+        backend.draw([(0, 0, &Cell::EMPTY)].into_iter()).unwrap();
+        backend.hide_cursor().unwrap();
+        backend.flush().unwrap();
+
+        // The underline in the top of row 1 moved up with its cell.
+        let display = backend.display();
+        for y in 0..12 {
+            for x in 0..4 {
+                assert_eq!(
+                    display.get_pixel(Point::new(x, y)),
+                    Some(Rgb888::BLACK),
+                    "({x}, {y})"
+                );
+            }
+        }
+    }
+
+    fn assert_clear_removes_decorations(
+        mut display0: MockDisplay<Rgb888>,
+        font_regular: MonoFont<'static>,
+        clip_to_cell: bool,
+    ) {
+        use ratatui::layout::Rect;
+        use ratatui::style::Stylize;
+        use ratatui::text::Line;
+        use ratatui::widgets::Clear;
+
+        let config = EmbeddedBackendConfig {
+            font_regular,
+            clip_to_cell,
+            ..Default::default()
+        };
+        let backend = EmbeddedBackend::new(&mut display0, config);
+        let mut terminal = Terminal::new(backend).expect("to create terminal");
+        let area = Rect::new(0, 0, 2, 1);
+        terminal
+            .draw(|frame| {
+                frame.render_widget(Line::from("ab".underlined().crossed_out()), area);
+            })
+            .expect("to draw");
+        terminal
+            .draw(|frame| frame.render_widget(Clear, area))
+            .expect("to draw");
+
+        let display = terminal.backend().display();
+        let char_size = font_regular.character_size;
+        for y in 0..char_size.height as i32 + 1 {
+            for x in 0..2 * char_size.width as i32 {
+                assert_ne!(
+                    display.get_pixel(Point::new(x, y)),
+                    Some(Rgb888::WHITE),
+                    "({x}, {y})"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn blank_cells_are_filled_per_row() {
+        let mut mock = MockDisplay::<Rgb888>::new();
+        let mut display = TestDisplay::new(&mut mock);
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display, config);
+        let mut blank = ratatui_core::buffer::Cell::EMPTY;
+        blank.set_bg(style::Color::Red);
+        let blank = &blank;
+        // 16 × 10 cells, drawn with one fill per row rather than one glyph per cell.
+        let screen = (0..10).flat_map(|y| (0..16).map(move |x| (x, y, blank)));
+        #[cfg(feature = "framebuffer")]
+        {
+            backend.buffer.draw_calls = 0;
+            backend.draw(screen).unwrap();
+            assert_eq!(backend.buffer.draw_calls, 10);
+        }
+        #[cfg(not(feature = "framebuffer"))]
+        {
+            backend.draw(screen).unwrap();
+            assert_eq!(backend.display().calls, 10);
+        }
+    }
+
+    #[cfg(feature = "framebuffer")]
+    #[rstest]
+    fn screenshot_encodes_bmp(mut display0: MockDisplay<Rgb888>) {
+        use ratatui::style::Stylize;
+        use ratatui::text::Line;
+
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            ..Default::default()
+        };
+        let backend = EmbeddedBackend::new(&mut display0, config);
+        let mut terminal = Terminal::new(backend).expect("to create terminal");
+        terminal
+            .draw(|frame| frame.render_widget(Line::from(" ".on_red()), frame.area()))
+            .expect("to draw");
+
+        let bmp = terminal.backend().screenshot();
+        let u32_at =
+            |offset: usize| u32::from_le_bytes(bmp[offset..offset + 4].try_into().unwrap());
+        assert_eq!(&bmp[..2], b"BM");
+        assert_eq!(u32_at(2) as usize, bmp.len());
+        assert_eq!(u32_at(18), 64);
+        assert_eq!(u32_at(22), 64);
+        assert_eq!(u16::from_le_bytes([bmp[28], bmp[29]]), 24);
+        // Rows are stored bottom to top, so the top-left pixel starts the last row.
+        let top_left = u32_at(10) as usize + 63 * 64 * 3;
+        assert_eq!(bmp[top_left..top_left + 3], [0, 0, 255]);
+    }
+
+    #[cfg(feature = "framebuffer")]
+    #[rstest]
+    fn static_buffer_backs_framebuffer(mut display0: MockDisplay<Rgb888>) {
+        use crate::framebuffer::StaticBuffer;
+        use ratatui::style::Stylize;
+        use ratatui::text::Line;
+
+        let mut buffer = StaticBuffer::<Rgb888, { 64 * 64 }>::new(Rgb888::BLACK);
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            ..Default::default()
+        };
+        let backend = EmbeddedBackend::with_buffer(&mut display0, &mut buffer, config).unwrap();
+        let mut terminal = Terminal::new(backend).expect("to create terminal");
+        terminal
+            .draw(|frame| frame.render_widget(Line::from(" ".on_red()), frame.area()))
+            .expect("to draw");
+
+        let display = terminal.backend().display();
+        assert_eq!(display.get_pixel(Point::new(0, 0)), Some(Rgb888::RED));
+        assert_eq!(display.get_pixel(Point::new(4, 0)), Some(Rgb888::BLACK));
+        drop(terminal);
+        assert_eq!(buffer[0], Rgb888::RED);
+    }
+
+    #[cfg(feature = "framebuffer")]
+    #[rstest]
+    fn static_buffer_must_fit_display(mut display0: MockDisplay<Rgb888>) {
+        use crate::framebuffer::StaticBuffer;
+
+        let mut buffer = StaticBuffer::<Rgb888, 64>::new(Rgb888::BLACK);
+        let result = EmbeddedBackend::with_buffer(
+            &mut display0,
+            &mut buffer,
+            EmbeddedBackendConfig::default(),
+        );
+        assert!(matches!(
+            result,
+            Err(crate::error::Error::BufferSize {
+                expected: 4096,
+                actual: 64
+            })
+        ));
+    }
+
+    #[cfg(feature = "framebuffer")]
+    #[test]
+    fn static_double_buffer_skips_unchanged_frames() {
+        use crate::framebuffer::StaticBuffer;
+        use ratatui_core::buffer::Cell;
+
+        let mut mock = MockDisplay::<Rgb888>::new();
+        let mut display = TestDisplay::new(&mut mock);
+        let mut buffer = StaticBuffer::<Rgb888, { 64 * 64 }>::new(Rgb888::BLACK);
+        let mut front = StaticBuffer::<Rgb888, { 64 * 64 }>::new(Rgb888::BLACK);
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            ..Default::default()
+        };
+        let mut backend =
+            EmbeddedBackend::with_double_buffer(&mut display, &mut buffer, &mut front, config)
+                .unwrap();
+        backend.hide_cursor().unwrap();
+        let mut cell = Cell::new("x");
+        cell.set_bg(ratatui::style::Color::Red);
+
+        backend.draw([(0, 0, &cell)].into_iter()).unwrap();
+        backend.flush().unwrap();
+        assert_eq!(backend.display().fills.len(), 1);
+
+        backend.display_mut().fills.clear();
+        backend.draw([(0, 0, &cell)].into_iter()).unwrap();
+        backend.flush().unwrap();
+        assert!(backend.display().fills.is_empty());
+    }
+
+    #[cfg(feature = "framebuffer")]
+    #[rstest]
+    fn caller_buffer_does_not_allocate_front_buffer(mut display0: MockDisplay<Rgb888>) {
+        use crate::framebuffer::StaticBuffer;
+
+        let mut buffer = StaticBuffer::<Rgb888, { 64 * 64 }>::new(Rgb888::BLACK);
+        let config = EmbeddedBackendConfig {
+            double_buffer: true,
+            ..Default::default()
+        };
+        let result = EmbeddedBackend::with_buffer(&mut display0, &mut buffer, config);
+        assert!(matches!(result, Err(crate::error::Error::Unsupported(_))));
+
+        let mut front = StaticBuffer::<Rgb888, 64>::new(Rgb888::BLACK);
+        let result = EmbeddedBackend::with_double_buffer(
+            &mut display0,
+            &mut buffer,
+            &mut front,
+            EmbeddedBackendConfig::default(),
+        );
+        assert!(matches!(
+            result,
+            Err(crate::error::Error::BufferSize {
+                expected: 4096,
+                actual: 64
+            })
+        ));
+    }
 }
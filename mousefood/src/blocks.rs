@@ -0,0 +1,89 @@
+//! Geometry of Unicode block elements.
+//!
+//! Block elements (`▁▂▃▄▅▆▇█`, `▏▎▍▌▋▊▉`, …) are used by ratatui's `Gauge`,
+//! `BarChart` and `Sparkline` to draw fills with sub-cell precision. Bitmap fonts
+//! often lack these glyphs or approximate them, so they can be drawn as
+//! rectangles covering the exact fraction of the cell instead.
+
+use embedded_graphics::geometry::{Point, Size};
+use embedded_graphics::primitives::Rectangle;
+
+/// Returns the filled area of `symbol` relative to the top-left of a cell of `size`,
+/// or `None` if `symbol` isn't a single-rectangle block element.
+pub(crate) fn block_element_rect(symbol: &str, size: Size) -> Option<Rectangle> {
+    let mut chars = symbol.chars();
+    let (Some(ch), None) = (chars.next(), chars.next()) else {
+        return None;
+    };
+    let Size { width, height } = size;
+    let eighths = |len: u32, n: u32| (len * n + 4) / 8;
+
+    let (top_left, size) = match ch {
+        // UPPER HALF BLOCK
+        '\u{2580}' => (Point::zero(), Size::new(width, height / 2)),
+        // LOWER ONE EIGHTH BLOCK ..= FULL BLOCK
+        '\u{2581}'..='\u{2588}' => {
+            let h = eighths(height, ch as u32 - 0x2580);
+            (Point::new(0, (height - h) as i32), Size::new(width, h))
+        }
+        // LEFT SEVEN EIGHTHS BLOCK ..= LEFT ONE EIGHTH BLOCK
+        '\u{2589}'..='\u{258F}' => (
+            Point::zero(),
+            Size::new(eighths(width, 0x2590 - ch as u32), height),
+        ),
+        // RIGHT HALF BLOCK
+        '\u{2590}' => {
+            let w = width / 2;
+            (Point::new((width - w) as i32, 0), Size::new(w, height))
+        }
+        // UPPER ONE EIGHTH BLOCK
+        '\u{2594}' => (Point::zero(), Size::new(width, eighths(height, 1))),
+        // RIGHT ONE EIGHTH BLOCK
+        '\u{2595}' => {
+            let w = eighths(width, 1);
+            (Point::new((width - w) as i32, 0), Size::new(w, height))
+        }
+        _ => return None,
+    };
+    Some(Rectangle::new(top_left, size))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    const CELL: Size = Size::new(8, 16);
+
+    #[rstest]
+    #[case("▀", (0, 0), (8, 8))]
+    #[case("▁", (0, 14), (8, 2))]
+    #[case("▄", (0, 8), (8, 8))]
+    #[case("▇", (0, 2), (8, 14))]
+    #[case("█", (0, 0), (8, 16))]
+    #[case("▉", (0, 0), (7, 16))]
+    #[case("▌", (0, 0), (4, 16))]
+    #[case("▏", (0, 0), (1, 16))]
+    #[case("▐", (4, 0), (4, 16))]
+    #[case("▔", (0, 0), (8, 2))]
+    #[case("▕", (7, 0), (1, 16))]
+    fn block_elements(
+        #[case] symbol: &str,
+        #[case] top_left: (i32, i32),
+        #[case] size: (u32, u32),
+    ) {
+        assert_eq!(
+            block_element_rect(symbol, CELL),
+            Some(Rectangle::new(top_left.into(), size.into()))
+        );
+    }
+
+    #[rstest]
+    #[case(" ")]
+    #[case("a")]
+    #[case("▌▌")]
+    #[case("░")]
+    fn non_block_elements(#[case] symbol: &str) {
+        assert_eq!(block_element_rect(symbol, CELL), None);
+    }
+}
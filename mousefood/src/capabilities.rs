@@ -0,0 +1,39 @@
+//! Hardware features of the underlying display.
+
+use alloc::boxed::Box;
+
+/// Driver callback that scrolls the display up by a number of pixel rows.
+pub type ScrollFn<D> = Box<dyn FnMut(&mut D, u32)>;
+
+/// Driver callback that turns hardware color inversion on or off.
+pub type InvertFn<D> = Box<dyn FnMut(&mut D, bool)>;
+
+/// Describes optional hardware features of the display.
+///
+/// Mousefood can't detect what a display driver supports, so the features are
+/// described here, as callbacks into the driver where needed. Code paths that can
+/// use a feature fall back to software when it isn't available.
+pub struct DisplayCapabilities<D> {
+    /// Scrolls the whole display content up by the given number of pixel rows.
+    ///
+    /// Used by [`EmbeddedBackend::scroll_up`](crate::EmbeddedBackend::scroll_up).
+    /// After scrolling, drawing at a point must still draw at that point on screen.
+    pub hw_scroll: Option<ScrollFn<D>>,
+    /// Inverts (`true`) or restores (`false`) all colors of the display.
+    ///
+    /// Used by [`EmbeddedBackend::set_inverted`](crate::EmbeddedBackend::set_inverted).
+    pub hw_invert: Option<InvertFn<D>>,
+    /// Whether the display can efficiently update a sub-rectangle (window) of the
+    /// screen, rather than only the whole screen at once.
+    pub windowing: bool,
+}
+
+impl<D> Default for DisplayCapabilities<D> {
+    fn default() -> Self {
+        Self {
+            hw_scroll: None,
+            hw_invert: None,
+            windowing: true,
+        }
+    }
+}
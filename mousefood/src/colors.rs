@@ -1,8 +1,7 @@
-use crate::macros::for_all_rgb_colors;
-#[cfg(feature = "lilygo-epd47")]
-use embedded_graphics::pixelcolor::Gray4;
+use crate::macros::{for_all_gray_colors, for_all_rgb_colors};
 use embedded_graphics::pixelcolor::{
-    Bgr555, Bgr565, Bgr666, Bgr888, BinaryColor, Rgb555, Rgb565, Rgb666, Rgb888, RgbColor,
+    Bgr555, Bgr565, Bgr666, Bgr888, BinaryColor, Gray2, Gray4, Gray8, Rgb555, Rgb565, Rgb666,
+    Rgb888, RgbColor,
 };
 use ratatui_core::style::Color;
 
@@ -45,6 +44,10 @@ pub struct ColorTheme {
     pub gray: Rgb888,
     /// ANSI dark gray mapping.
     pub dark_gray: Rgb888,
+    /// Foreground color of disabled (greyed-out) UI elements.
+    pub disabled_fg: Rgb888,
+    /// Background color of disabled (greyed-out) UI elements.
+    pub disabled_bg: Rgb888,
 }
 
 impl Default for ColorTheme {
@@ -75,6 +78,8 @@ impl ColorTheme {
             light_cyan: Rgb888::new(Rgb888::MAX_R / 2, Rgb888::MAX_G, Rgb888::MAX_B),
             gray: Rgb888::new(Rgb888::MAX_R / 2, Rgb888::MAX_G / 2, Rgb888::MAX_B / 2),
             dark_gray: Rgb888::new(170, 170, 170),
+            disabled_fg: Rgb888::new(Rgb888::MAX_R / 2, Rgb888::MAX_G / 2, Rgb888::MAX_B / 2),
+            disabled_bg: Rgb888::BLACK,
         }
     }
 
@@ -99,9 +104,246 @@ impl ColorTheme {
             light_cyan: Rgb888::new(0x7d, 0xcf, 0xff), // Same as cyan
             gray: Rgb888::new(0xc0, 0xca, 0xf5),       // Light blue-white
             dark_gray: Rgb888::new(0x41, 0x48, 0x68),  // Dark blue-gray
+            disabled_fg: Rgb888::new(0x56, 0x5f, 0x89), // Muted comment gray
+            disabled_bg: Rgb888::new(0x1a, 0x1b, 0x26), // Same as background
         }
     }
 
+    /// Catppuccin Mocha color theme - the darkest Catppuccin flavor with pastel tones.
+    pub const fn catppuccin_mocha() -> Self {
+        Self {
+            foreground: Rgb888::new(0xcd, 0xd6, 0xf4),    // Text
+            background: Rgb888::new(0x1e, 0x1e, 0x2e),    // Base
+            white: Rgb888::new(0xa6, 0xad, 0xc8),         // Subtext 0
+            black: Rgb888::new(0x45, 0x47, 0x5a),         // Surface 1
+            red: Rgb888::new(0xf3, 0x8b, 0xa8),           // Red
+            green: Rgb888::new(0xa6, 0xe3, 0xa1),         // Green
+            yellow: Rgb888::new(0xf9, 0xe2, 0xaf),        // Yellow
+            blue: Rgb888::new(0x89, 0xb4, 0xfa),          // Blue
+            magenta: Rgb888::new(0xf5, 0xc2, 0xe7),       // Pink
+            cyan: Rgb888::new(0x94, 0xe2, 0xd5),          // Teal
+            light_red: Rgb888::new(0xf3, 0x8b, 0xa8),     // Same as red
+            light_green: Rgb888::new(0xa6, 0xe3, 0xa1),   // Same as green
+            light_yellow: Rgb888::new(0xf9, 0xe2, 0xaf),  // Same as yellow
+            light_blue: Rgb888::new(0x89, 0xb4, 0xfa),    // Same as blue
+            light_magenta: Rgb888::new(0xf5, 0xc2, 0xe7), // Same as magenta
+            light_cyan: Rgb888::new(0x94, 0xe2, 0xd5),    // Same as cyan
+            gray: Rgb888::new(0xba, 0xc2, 0xde),          // Subtext 1
+            dark_gray: Rgb888::new(0x58, 0x5b, 0x70),     // Surface 2
+            disabled_fg: Rgb888::new(0x6c, 0x70, 0x86),   // Overlay 0
+            disabled_bg: Rgb888::new(0x1e, 0x1e, 0x2e),   // Same as background
+        }
+    }
+
+    /// Gruvbox dark color theme - a retro theme with warm, earthy tones.
+    pub const fn gruvbox_dark() -> Self {
+        Self {
+            foreground: Rgb888::new(0xeb, 0xdb, 0xb2), // Light cream text
+            background: Rgb888::new(0x28, 0x28, 0x28), // Dark gray-brown background
+            white: Rgb888::new(0xeb, 0xdb, 0xb2),      // Light cream
+            black: Rgb888::new(0x28, 0x28, 0x28),      // Same as background
+            red: Rgb888::new(0xcc, 0x24, 0x1d),        // Red
+            green: Rgb888::new(0x98, 0x97, 0x1a),      // Olive green
+            yellow: Rgb888::new(0xd7, 0x99, 0x21),     // Mustard yellow
+            blue: Rgb888::new(0x45, 0x85, 0x88),       // Muted blue
+            magenta: Rgb888::new(0xb1, 0x62, 0x86),    // Purple
+            cyan: Rgb888::new(0x68, 0x9d, 0x6a),       // Aqua
+            light_red: Rgb888::new(0xfb, 0x49, 0x34),  // Bright red
+            light_green: Rgb888::new(0xb8, 0xbb, 0x26), // Bright green
+            light_yellow: Rgb888::new(0xfa, 0xbd, 0x2f), // Bright yellow
+            light_blue: Rgb888::new(0x83, 0xa5, 0x98), // Bright blue
+            light_magenta: Rgb888::new(0xd3, 0x86, 0x9b), // Bright purple
+            light_cyan: Rgb888::new(0x8e, 0xc0, 0x7c), // Bright aqua
+            gray: Rgb888::new(0xa8, 0x99, 0x84),       // Beige gray
+            dark_gray: Rgb888::new(0x92, 0x83, 0x74),  // Brown gray
+            disabled_fg: Rgb888::new(0x66, 0x5c, 0x54), // Dark brown gray
+            disabled_bg: Rgb888::new(0x28, 0x28, 0x28), // Same as background
+        }
+    }
+
+    /// Nord color theme - an arctic theme with cool, bluish tones.
+    pub const fn nord() -> Self {
+        Self {
+            foreground: Rgb888::new(0xd8, 0xde, 0xe9), // Snow storm text
+            background: Rgb888::new(0x2e, 0x34, 0x40), // Polar night background
+            white: Rgb888::new(0xec, 0xef, 0xf4),      // Brightest snow storm
+            black: Rgb888::new(0x3b, 0x42, 0x52),      // Polar night
+            red: Rgb888::new(0xbf, 0x61, 0x6a),        // Aurora red
+            green: Rgb888::new(0xa3, 0xbe, 0x8c),      // Aurora green
+            yellow: Rgb888::new(0xeb, 0xcb, 0x8b),     // Aurora yellow
+            blue: Rgb888::new(0x81, 0xa1, 0xc1),       // Frost blue
+            magenta: Rgb888::new(0xb4, 0x8e, 0xad),    // Aurora purple
+            cyan: Rgb888::new(0x88, 0xc0, 0xd0),       // Frost cyan
+            light_red: Rgb888::new(0xbf, 0x61, 0x6a),  // Same as red
+            light_green: Rgb888::new(0xa3, 0xbe, 0x8c), // Same as green
+            light_yellow: Rgb888::new(0xeb, 0xcb, 0x8b), // Same as yellow
+            light_blue: Rgb888::new(0x81, 0xa1, 0xc1), // Same as blue
+            light_magenta: Rgb888::new(0xb4, 0x8e, 0xad), // Same as magenta
+            light_cyan: Rgb888::new(0x8f, 0xbc, 0xbb), // Frost teal
+            gray: Rgb888::new(0xe5, 0xe9, 0xf0),       // Snow storm
+            dark_gray: Rgb888::new(0x4c, 0x56, 0x6a),  // Lightest polar night
+            disabled_fg: Rgb888::new(0x4c, 0x56, 0x6a), // Same as dark gray
+            disabled_bg: Rgb888::new(0x2e, 0x34, 0x40), // Same as background
+        }
+    }
+
+    /// Grayscale theme for monochrome, grayscale and e-paper displays.
+    ///
+    /// Every ANSI color is replaced by a gray of the same luminance
+    /// (`0.299 R + 0.587 G + 0.114 B` of the [`ColorTheme::ansi`] colors), so colors
+    /// that would all collapse to black or white stay distinguishable when
+    /// converted to `Gray8` or dithered to `BinaryColor`.
+    pub const fn grayscale() -> Self {
+        const fn luma(level: u8) -> Rgb888 {
+            Rgb888::new(level, level, level)
+        }
+        Self {
+            foreground: luma(255),
+            background: luma(0),
+            white: luma(255),
+            black: luma(0),
+            red: luma(76),
+            green: luma(150),
+            yellow: luma(226),
+            blue: luma(29),
+            magenta: luma(105),
+            cyan: luma(179),
+            light_red: luma(165),
+            light_green: luma(202),
+            light_yellow: luma(240),
+            light_blue: luma(142),
+            light_magenta: luma(180),
+            light_cyan: luma(217),
+            gray: luma(127),
+            dark_gray: luma(170),
+            disabled_fg: luma(96),
+            disabled_bg: luma(0),
+        }
+    }
+
+    /// Builds a theme from the 8 normal ANSI colors, deriving the bright variants.
+    ///
+    /// `base8` is in ANSI order: black, red, green, yellow, blue, magenta, cyan, white.
+    /// Each bright color is its base color blended halfway toward white, like in
+    /// [`ColorTheme::ansi`]. Following ANSI, `white` is the bright variant of the
+    /// last entry, which itself becomes `gray`, and `dark_gray` is the bright black.
+    pub fn with_auto_bright(base8: [Rgb888; 8]) -> Self {
+        let [black, red, green, yellow, blue, magenta, cyan, white] = base8;
+        Self {
+            foreground: white,
+            background: black,
+            white: brighten(white),
+            black,
+            red,
+            green,
+            yellow,
+            blue,
+            magenta,
+            cyan,
+            light_red: brighten(red),
+            light_green: brighten(green),
+            light_yellow: brighten(yellow),
+            light_blue: brighten(blue),
+            light_magenta: brighten(magenta),
+            light_cyan: brighten(cyan),
+            gray: white,
+            dark_gray: brighten(black),
+            disabled_fg: dim_color(white),
+            disabled_bg: black,
+        }
+    }
+
+    /// Builds a theme from a palette of 18 colors.
+    ///
+    /// The colors are in field order: foreground, background, white, black, red,
+    /// green, yellow, blue, magenta, cyan, light_red, light_green, light_yellow,
+    /// light_blue, light_magenta, light_cyan, gray, dark_gray. The disabled colors
+    /// are derived: `disabled_fg` is the dimmed foreground and `disabled_bg` is the
+    /// background.
+    pub fn from_rgb_palette(palette: [Rgb888; 18]) -> Self {
+        let [
+            foreground,
+            background,
+            white,
+            black,
+            red,
+            green,
+            yellow,
+            blue,
+            magenta,
+            cyan,
+            light_red,
+            light_green,
+            light_yellow,
+            light_blue,
+            light_magenta,
+            light_cyan,
+            gray,
+            dark_gray,
+        ] = palette;
+        Self {
+            foreground,
+            background,
+            white,
+            black,
+            red,
+            green,
+            yellow,
+            blue,
+            magenta,
+            cyan,
+            light_red,
+            light_green,
+            light_yellow,
+            light_blue,
+            light_magenta,
+            light_cyan,
+            gray,
+            dark_gray,
+            disabled_fg: dim_color(foreground),
+            disabled_bg: background,
+        }
+    }
+
+    /// Returns the theme's colors in the order of [`ColorTheme::from_rgb_palette`].
+    ///
+    /// The disabled colors aren't part of the palette.
+    pub fn to_rgb_palette(&self) -> [Rgb888; 18] {
+        [
+            self.foreground,
+            self.background,
+            self.white,
+            self.black,
+            self.red,
+            self.green,
+            self.yellow,
+            self.blue,
+            self.magenta,
+            self.cyan,
+            self.light_red,
+            self.light_green,
+            self.light_yellow,
+            self.light_blue,
+            self.light_magenta,
+            self.light_cyan,
+            self.gray,
+            self.dark_gray,
+        ]
+    }
+
+    /// Returns the palette color (see [`ColorTheme::to_rgb_palette`]) nearest to
+    /// `color` by Euclidean distance in RGB space.
+    pub fn quantize(&self, color: Rgb888) -> Rgb888 {
+        let distance = |other: &Rgb888| {
+            let d = |a: u8, b: u8| (a as i32 - b as i32).pow(2);
+            d(color.r(), other.r()) + d(color.g(), other.g()) + d(color.b(), other.b())
+        };
+        self.to_rgb_palette()
+            .into_iter()
+            .min_by_key(distance)
+            .unwrap_or(color)
+    }
+
     pub(crate) fn resolve(&self, color: Color, color_type: TermColorType) -> Rgb888 {
         match color {
             Color::Reset => match color_type {
@@ -127,11 +369,51 @@ impl ColorTheme {
             Color::DarkGray => self.dark_gray,
 
             Color::Rgb(r, g, b) => Rgb888::new(r, g, b),
-            Color::Indexed(_) => Rgb888::BLACK,
+            Color::Indexed(index) => self.resolve_indexed(index),
+        }
+    }
+
+    /// Maps an xterm 256-color palette index to a color.
+    ///
+    /// Indices 0–15 use the themed ANSI colors, 16–231 the 6×6×6 color cube and
+    /// 232–255 the grayscale ramp.
+    fn resolve_indexed(&self, index: u8) -> Rgb888 {
+        match index {
+            0 => self.black,
+            1 => self.red,
+            2 => self.green,
+            3 => self.yellow,
+            4 => self.blue,
+            5 => self.magenta,
+            6 => self.cyan,
+            7 => self.gray,
+            8 => self.dark_gray,
+            9 => self.light_red,
+            10 => self.light_green,
+            11 => self.light_yellow,
+            12 => self.light_blue,
+            13 => self.light_magenta,
+            14 => self.light_cyan,
+            15 => self.white,
+            16..=231 => {
+                let i = index - 16;
+                Rgb888::new(
+                    XTERM_CUBE_LEVELS[(i / 36) as usize],
+                    XTERM_CUBE_LEVELS[(i / 6 % 6) as usize],
+                    XTERM_CUBE_LEVELS[(i % 6) as usize],
+                )
+            }
+            232..=255 => {
+                let level = 8 + 10 * (index - 232);
+                Rgb888::new(level, level, level)
+            }
         }
     }
 }
 
+/// Channel intensities of the xterm 6×6×6 color cube.
+const XTERM_CUBE_LEVELS: [u8; 6] = [0x00, 0x5f, 0x87, 0xaf, 0xd7, 0xff];
+
 #[derive(Clone, Copy)]
 pub enum TermColorType {
     Foreground,
@@ -164,6 +446,8 @@ macro_rules! impl_from_term_color {
 }
 
 for_all_rgb_colors!(impl_from_term_color);
+// Gray levels are the luma of the RGB color.
+for_all_gray_colors!(impl_from_term_color);
 
 impl<'a> From<TermColor<'a>> for BinaryColor {
     fn from(color: TermColor<'a>) -> Self {
@@ -178,23 +462,126 @@ impl<'a> From<TermColor<'a>> for BinaryColor {
     }
 }
 
-/// Helper function to dim a single u8 component by halving it.
-fn dim_u8(v: u8) -> u8 {
-    v >> 1
+/// Brighten the color by moving each RGB component halfway toward its maximum.
+fn brighten(color: Rgb888) -> Rgb888 {
+    let up = |v: u8| v + ((u8::MAX - v) >> 1);
+    Rgb888::new(up(color.r()), up(color.g()), up(color.b()))
 }
 
 /// Dim the color by halving each RGB component.
 ///
 /// This is a simple way to create a "darker" version
-/// of the color.
+/// of the color. With the `gamma-correct` feature the
+/// halving happens in linear light instead.
 pub fn dim_color<C>(color: C) -> C
+where
+    C: Into<Rgb888> + From<Rgb888>,
+{
+    blend_colors(color, Rgb888::BLACK.into(), 128)
+}
+
+/// Convert an `Rgb888` to the equivalent ratatui color.
+pub(crate) fn rgb_color(color: Rgb888) -> Color {
+    Color::Rgb(color.r(), color.g(), color.b())
+}
+
+/// Map each RGB component of the color through `lut`.
+pub(crate) fn apply_lut(color: Rgb888, lut: &[u8; 256]) -> Rgb888 {
+    Rgb888::new(
+        lut[color.r() as usize],
+        lut[color.g() as usize],
+        lut[color.b() as usize],
+    )
+}
+
+/// Invert the color by flipping all bits of each RGB component.
+#[cfg(feature = "framebuffer")]
+pub(crate) fn invert_color<C>(color: C) -> C
 where
     C: Into<Rgb888> + From<Rgb888>,
 {
     let rgb: Rgb888 = color.into();
-    Rgb888::new(dim_u8(rgb.r()), dim_u8(rgb.g()), dim_u8(rgb.b())).into()
+    Rgb888::new(!rgb.r(), !rgb.g(), !rgb.b()).into()
+}
+
+/// Blend `from` toward `to`, where `amount` 0 is `from` and 255 is `to`.
+///
+/// All compositing goes through this function. With the `gamma-correct` feature
+/// the blend happens in linear light, so e.g. a 50% mix of black and white gives
+/// the perceptual mid-gray (188) rather than 128.
+pub fn blend_colors<C>(from: C, to: C, amount: u8) -> C
+where
+    C: Into<Rgb888> + From<Rgb888>,
+{
+    let (from, to): (Rgb888, Rgb888) = (from.into(), to.into());
+    Rgb888::new(
+        blend_u8(from.r(), to.r(), amount),
+        blend_u8(from.g(), to.g(), amount),
+        blend_u8(from.b(), to.b(), amount),
+    )
+    .into()
+}
+
+#[cfg(not(feature = "gamma-correct"))]
+fn blend_u8(from: u8, to: u8, amount: u8) -> u8 {
+    let amount = amount as u32;
+    ((from as u32 * (255 - amount) + to as u32 * amount + 127) / 255) as u8
 }
 
+#[cfg(feature = "gamma-correct")]
+fn blend_u8(from: u8, to: u8, amount: u8) -> u8 {
+    let amount = amount as u32;
+    let from = SRGB_TO_LINEAR[from as usize] as u32;
+    let to = SRGB_TO_LINEAR[to as usize] as u32;
+    linear_to_srgb(((from * (255 - amount) + to * amount + 127) / 255) as u16)
+}
+
+/// Returns the sRGB value whose linear intensity is closest to `linear`.
+#[cfg(feature = "gamma-correct")]
+fn linear_to_srgb(linear: u16) -> u8 {
+    let above = SRGB_TO_LINEAR.partition_point(|&v| v < linear);
+    if above == 0 {
+        return 0;
+    }
+    if above == SRGB_TO_LINEAR.len() {
+        return u8::MAX;
+    }
+    let below = above - 1;
+    if linear - SRGB_TO_LINEAR[below] <= SRGB_TO_LINEAR[above] - linear {
+        below as u8
+    } else {
+        above as u8
+    }
+}
+
+/// Linear light intensity (0–65535) of every sRGB channel value.
+#[cfg(feature = "gamma-correct")]
+#[rustfmt::skip]
+const SRGB_TO_LINEAR: [u16; 256] = [
+    0, 20, 40, 60, 80, 99, 119, 139, 159, 179, 199, 219,
+    241, 264, 288, 313, 340, 367, 396, 427, 458, 491, 526, 562,
+    599, 637, 677, 718, 761, 805, 851, 898, 947, 997, 1048, 1101,
+    1156, 1212, 1270, 1330, 1391, 1453, 1517, 1583, 1651, 1720, 1790, 1863,
+    1937, 2013, 2090, 2170, 2250, 2333, 2418, 2504, 2592, 2681, 2773, 2866,
+    2961, 3058, 3157, 3258, 3360, 3464, 3570, 3678, 3788, 3900, 4014, 4129,
+    4247, 4366, 4488, 4611, 4736, 4864, 4993, 5124, 5257, 5392, 5530, 5669,
+    5810, 5953, 6099, 6246, 6395, 6547, 6700, 6856, 7014, 7174, 7335, 7500,
+    7666, 7834, 8004, 8177, 8352, 8528, 8708, 8889, 9072, 9258, 9445, 9635,
+    9828, 10022, 10219, 10417, 10619, 10822, 11028, 11235, 11446, 11658, 11873, 12090,
+    12309, 12530, 12754, 12980, 13209, 13440, 13673, 13909, 14146, 14387, 14629, 14874,
+    15122, 15371, 15623, 15878, 16135, 16394, 16656, 16920, 17187, 17456, 17727, 18001,
+    18277, 18556, 18837, 19121, 19407, 19696, 19987, 20281, 20577, 20876, 21177, 21481,
+    21787, 22096, 22407, 22721, 23038, 23357, 23678, 24002, 24329, 24658, 24990, 25325,
+    25662, 26001, 26344, 26688, 27036, 27386, 27739, 28094, 28452, 28813, 29176, 29542,
+    29911, 30282, 30656, 31033, 31412, 31794, 32179, 32567, 32957, 33350, 33745, 34143,
+    34544, 34948, 35355, 35764, 36176, 36591, 37008, 37429, 37852, 38278, 38706, 39138,
+    39572, 40009, 40449, 40891, 41337, 41785, 42236, 42690, 43147, 43606, 44069, 44534,
+    45002, 45473, 45947, 46423, 46903, 47385, 47871, 48359, 48850, 49344, 49841, 50341,
+    50844, 51349, 51858, 52369, 52884, 53401, 53921, 54445, 54971, 55500, 56032, 56567,
+    57105, 57646, 58190, 58737, 59287, 59840, 60396, 60955, 61517, 62082, 62650, 63221,
+    63795, 64372, 64952, 65535,
+];
+
 #[cfg(feature = "epd-weact")]
 impl<'a> From<TermColor<'a>> for weact_studio_epd::Color {
     fn from(color: TermColor<'a>) -> Self {
@@ -243,11 +630,31 @@ impl From<TermColor<'_>> for epd_waveshare::color::TriColor {
     }
 }
 
-#[cfg(feature = "lilygo-epd47")]
-impl<'a> From<TermColor<'a>> for Gray4 {
-    fn from(color: TermColor<'a>) -> Self {
-        let rgb: Rgb888 = color.into();
-        rgb.into()
+/// Maps the color to the nearest of the seven colors of ACeP panels.
+#[cfg(feature = "epd-waveshare")]
+impl From<TermColor<'_>> for epd_waveshare::color::OctColor {
+    fn from(color: TermColor) -> Self {
+        use epd_waveshare::color::OctColor;
+
+        let rgb = color.to_rgb888();
+        let distance = |device: &OctColor| {
+            let (r, g, b) = device.rgb();
+            (r.abs_diff(rgb.r()) as u32).pow(2)
+                + (g.abs_diff(rgb.g()) as u32).pow(2)
+                + (b.abs_diff(rgb.b()) as u32).pow(2)
+        };
+        [
+            OctColor::Black,
+            OctColor::White,
+            OctColor::Green,
+            OctColor::Blue,
+            OctColor::Red,
+            OctColor::Yellow,
+            OctColor::Orange,
+        ]
+        .into_iter()
+        .min_by_key(distance)
+        .unwrap_or(OctColor::White)
     }
 }
 
@@ -316,6 +723,111 @@ mod tests {
     }
     for_all_rgb_colors!(into_eg_color);
 
+    #[rstest]
+    #[case(0, TEST_THEME.black)]
+    #[case(1, TEST_THEME.red)]
+    #[case(8, TEST_THEME.dark_gray)]
+    #[case(15, TEST_THEME.white)]
+    #[case(16, Rgb888::new(0, 0, 0))]
+    #[case(17, Rgb888::new(0, 0, 0x5f))]
+    #[case(196, Rgb888::new(0xff, 0, 0))]
+    #[case(231, Rgb888::new(0xff, 0xff, 0xff))]
+    #[case(232, Rgb888::new(8, 8, 8))]
+    #[case(244, Rgb888::new(128, 128, 128))]
+    #[case(255, Rgb888::new(238, 238, 238))]
+    fn resolve_indexed(#[case] index: u8, #[case] expected: Rgb888) {
+        assert_eq!(TEST_THEME.resolve(Indexed(index), Foreground), expected);
+    }
+
+    #[rstest]
+    #[case::ansi(ColorTheme::ansi())]
+    #[case::tokyo_night(ColorTheme::tokyo_night())]
+    #[case::catppuccin_mocha(ColorTheme::catppuccin_mocha())]
+    #[case::gruvbox_dark(ColorTheme::gruvbox_dark())]
+    #[case::nord(ColorTheme::nord())]
+    #[case::grayscale(ColorTheme::grayscale())]
+    fn named_colors_are_not_black(#[case] theme: ColorTheme) {
+        let named = [
+            Reset,
+            White,
+            Red,
+            Green,
+            Yellow,
+            Blue,
+            Magenta,
+            Cyan,
+            LightRed,
+            LightGreen,
+            LightYellow,
+            LightBlue,
+            LightMagenta,
+            LightCyan,
+            Gray,
+            DarkGray,
+        ];
+        for color in named {
+            assert_ne!(theme.resolve(color, Foreground), Rgb888::BLACK, "{color:?}");
+        }
+    }
+
+    #[rstest]
+    #[case::white(Rgb(255, 255, 255), 255)]
+    #[case::black(Rgb(0, 0, 0), 0)]
+    #[case::mid_red(Rgb(128, 0, 0), 39)]
+    fn into_gray_uses_luma(#[case] color_from: Color, #[case] luma: u8) {
+        use embedded_graphics::pixelcolor::GrayColor;
+
+        let gray8 = Gray8::from(themed(Foreground, color_from));
+        let gray4 = Gray4::from(themed(Foreground, color_from));
+        let gray2 = Gray2::from(themed(Foreground, color_from));
+        assert_eq!(gray8.luma(), luma);
+        assert_eq!(gray4.luma(), luma >> 4);
+        assert_eq!(gray2.luma(), luma >> 6);
+    }
+
+    #[test]
+    fn grayscale_keeps_colors_apart() {
+        let theme = ColorTheme::grayscale();
+        let red = Gray8::from(theme.resolve(Red, Foreground));
+        let green = Gray8::from(theme.resolve(Green, Foreground));
+        let blue = Gray8::from(theme.resolve(Blue, Foreground));
+        assert_ne!(red, green);
+        assert_ne!(green, blue);
+        assert_ne!(red, blue);
+    }
+
+    #[test]
+    fn blend_black_and_white() {
+        let mid = blend_colors(Rgb888::BLACK, Rgb888::WHITE, 128);
+        #[cfg(feature = "gamma-correct")]
+        assert_eq!(mid, Rgb888::new(188, 188, 188));
+        #[cfg(not(feature = "gamma-correct"))]
+        assert_eq!(mid, Rgb888::new(128, 128, 128));
+
+        assert_eq!(blend_colors(Rgb888::RED, Rgb888::BLUE, 0), Rgb888::RED);
+        assert_eq!(blend_colors(Rgb888::RED, Rgb888::BLUE, 255), Rgb888::BLUE);
+    }
+
+    #[test]
+    fn auto_bright_derives_brighter_colors() {
+        let base = ColorTheme::ansi();
+        let theme = ColorTheme::with_auto_bright([
+            base.black,
+            Rgb888::new(200, 40, 40),
+            base.green,
+            base.yellow,
+            base.blue,
+            base.magenta,
+            base.cyan,
+            base.white,
+        ]);
+        let luma = |c: Rgb888| c.r() as u32 + c.g() as u32 + c.b() as u32;
+        assert_eq!(theme.red, Rgb888::new(200, 40, 40));
+        assert_eq!(theme.light_red, Rgb888::new(227, 147, 147));
+        assert!(luma(theme.light_red) > luma(theme.red));
+        assert!(luma(theme.dark_gray) > luma(theme.black));
+    }
+
     #[rstest]
     #[case(Foreground, Black, BinaryColor::Off)]
     #[case(Background, Black, BinaryColor::Off)]
@@ -395,4 +907,49 @@ mod tests {
         let output: epd_waveshare::color::TriColor = themed(color_type, color_from).into();
         assert_eq!(output, color_into);
     }
+
+    #[cfg(feature = "epd-waveshare")]
+    #[rstest]
+    #[case(Black, epd_waveshare::color::OctColor::Black)]
+    #[case(White, epd_waveshare::color::OctColor::White)]
+    #[case(Red, epd_waveshare::color::OctColor::Red)]
+    #[case(Green, epd_waveshare::color::OctColor::Green)]
+    #[case(Blue, epd_waveshare::color::OctColor::Blue)]
+    #[case(Yellow, epd_waveshare::color::OctColor::Yellow)]
+    #[case(Rgb(240, 120, 10), epd_waveshare::color::OctColor::Orange)]
+    #[case(Rgb(20, 30, 40), epd_waveshare::color::OctColor::Black)]
+    fn into_waveshare_octcolor(
+        #[case] color_from: Color,
+        #[case] color_into: epd_waveshare::color::OctColor,
+    ) {
+        let output: epd_waveshare::color::OctColor = themed(Foreground, color_from).into();
+        assert_eq!(output, color_into);
+    }
+
+    #[test]
+    fn rgb_palette_round_trip() {
+        let theme = ColorTheme::tokyo_night();
+        let palette = theme.to_rgb_palette();
+        assert_eq!(palette[4], theme.red);
+        assert_eq!(palette[17], theme.dark_gray);
+
+        let rebuilt = ColorTheme::from_rgb_palette(palette);
+        assert_eq!(rebuilt.to_rgb_palette(), palette);
+        assert_eq!(rebuilt.disabled_bg, theme.background);
+    }
+
+    #[rstest]
+    #[case::catppuccin_mocha(ColorTheme::catppuccin_mocha())]
+    #[case::gruvbox_dark(ColorTheme::gruvbox_dark())]
+    #[case::nord(ColorTheme::nord())]
+    fn presets_have_distinct_foreground(#[case] theme: ColorTheme) {
+        assert_ne!(theme.background, theme.foreground);
+    }
+
+    #[test]
+    fn quantize_near_red() {
+        let theme = ColorTheme::ansi();
+        assert_eq!(theme.quantize(Rgb888::new(0xa0, 0x10, 0x08)), theme.red);
+        assert_eq!(theme.quantize(theme.cyan), theme.cyan);
+    }
 }
@@ -1,13 +1,17 @@
 //! Cursor configuration, styles, and rendering.
 
+use crate::scale::{Rotation, Scaled, scale_rect};
+use embedded_graphics::Pixel;
 use embedded_graphics::draw_target::DrawTarget;
-use embedded_graphics::geometry;
+use embedded_graphics::geometry::{self, Dimensions};
 use embedded_graphics::pixelcolor::{PixelColor, Rgb888};
 use embedded_graphics::prelude::RgbColor;
+use embedded_graphics::primitives::Rectangle;
 use ratatui_core::layout;
 
 /// How the cursor is rendered on screen.
 #[derive(Clone, Copy, PartialEq)]
+#[non_exhaustive]
 pub enum CursorStyle {
     /// Invert all pixels in the character cell (requires framebuffer).
     /// Falls back to `Underline` without framebuffer.
@@ -18,6 +22,104 @@ pub enum CursorStyle {
     Outline,
     /// Corner brackets — top-left and bottom-right corners.
     Japanese,
+    /// Solid block filling the character cell.
+    Block,
+    /// Vertical bar at the left edge of the character cell.
+    Bar,
+    /// Block filling the lower half of the character cell.
+    HalfBlock,
+    /// Drawn by [`EmbeddedBackendConfig::cursor_renderer`].
+    /// Falls back to `Underline` without a renderer.
+    ///
+    /// [`EmbeddedBackendConfig::cursor_renderer`]: crate::EmbeddedBackendConfig::cursor_renderer
+    Custom,
+}
+
+/// Draws a [`CursorStyle::Custom`] cursor.
+///
+/// Receives the display as a [`CursorTarget`], the top-left corner of the cursor cell
+/// and the cell width and height in pixels.
+///
+/// # Examples
+///
+/// A diamond centered in the cell:
+///
+/// ```rust
+/// use mousefood::{CursorConfig, CursorFn, CursorStyle};
+/// use mousefood::embedded_graphics::mock_display::MockDisplay;
+/// use mousefood::embedded_graphics::prelude::*;
+/// use mousefood::embedded_graphics::primitives::{PrimitiveStyle, Triangle};
+/// use mousefood::prelude::*;
+///
+/// let diamond: CursorFn<MockDisplay<Rgb888>> = Box::new(|display, top_left, w, h| {
+///     let center = top_left + Point::new(w / 2, h / 2);
+///     let (left, right) = (center - Point::new(w / 2, 0), center + Point::new(w / 2, 0));
+///     let style = PrimitiveStyle::with_fill(Rgb888::YELLOW);
+///     Triangle::new(left, right, center - Point::new(0, h / 2))
+///         .into_styled(style)
+///         .draw(display)?;
+///     Triangle::new(left, right, center + Point::new(0, h / 2))
+///         .into_styled(style)
+///         .draw(display)
+/// });
+/// let config = EmbeddedBackendConfig {
+///     cursor: CursorConfig {
+///         style: CursorStyle::Custom,
+///         ..Default::default()
+///     },
+///     cursor_renderer: Some(diamond),
+///     ..Default::default()
+/// };
+/// ```
+pub type CursorFn<D> = alloc::boxed::Box<
+    dyn FnMut(
+        &mut CursorTarget<'_, D>,
+        geometry::Point,
+        i32,
+        i32,
+    ) -> Result<(), <D as DrawTarget>::Error>,
+>;
+
+/// The display as seen by a [`CursorFn`].
+///
+/// Drawing to it applies the
+/// [`rotation`](crate::EmbeddedBackendConfig::rotation) of the terminal, so the
+/// renderer can draw in terminal coordinates however the display is mounted.
+pub struct CursorTarget<'a, D>(Scaled<'a, D>);
+
+impl<'a, D: DrawTarget> CursorTarget<'a, D> {
+    pub(crate) fn new(display: &'a mut D, rotation: crate::Rotation) -> Self {
+        Self(Scaled::uniform(display, 1, rotation))
+    }
+}
+
+impl<D: DrawTarget> Dimensions for CursorTarget<'_, D> {
+    fn bounding_box(&self) -> Rectangle {
+        self.0.bounding_box()
+    }
+}
+
+impl<D: DrawTarget> DrawTarget for CursorTarget<'_, D> {
+    type Color = D::Color;
+    type Error = D::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        self.0.draw_iter(pixels)
+    }
+
+    fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        self.0.fill_contiguous(area, colors)
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        self.0.fill_solid(area, color)
+    }
 }
 
 /// Cursor appearance and behavior.
@@ -25,10 +127,24 @@ pub enum CursorStyle {
 pub struct CursorConfig {
     /// Visual style of the cursor.
     pub style: CursorStyle,
-    /// Whether the cursor blinks. Uses `BlinkConfig::slow` timing.
+    /// Whether the cursor blinks. Uses `BlinkConfig::slow` timing unless
+    /// [`blink_timing`](Self::blink_timing) is set.
     pub blink: bool,
+    /// Blink timing of the cursor, independent of blinking text.
+    ///
+    /// `None` blinks the cursor together with [`Modifier::SLOW_BLINK`] text. Only
+    /// used with the `blink` feature.
+    ///
+    /// [`Modifier::SLOW_BLINK`]: ratatui_core::style::Modifier::SLOW_BLINK
+    pub blink_timing: Option<crate::backend::BlinkTiming>,
     /// Cursor color for non-inverse styles.
     pub color: Rgb888,
+    /// Whether an `Inverse` cursor over a `REVERSED` cell only inverts the outline
+    /// of the cell.
+    ///
+    /// Inverting the whole cell would undo the reversal, so the cursor would look
+    /// like an ordinary cell. Requires the framebuffer.
+    pub outline_on_reversed: bool,
 }
 
 impl Default for CursorConfig {
@@ -36,7 +152,9 @@ impl Default for CursorConfig {
         Self {
             style: CursorStyle::Inverse,
             blink: true,
+            blink_timing: None,
             color: Rgb888::WHITE,
+            outline_on_reversed: true,
         }
     }
 }
@@ -45,6 +163,8 @@ pub(crate) struct Cursor {
     pub visible: bool,
     pub position: layout::Position,
     pub config: CursorConfig,
+    /// Factor the rendered output is scaled up by.
+    pub scale: u32,
 }
 
 impl Cursor {
@@ -53,29 +173,49 @@ impl Cursor {
             visible: false,
             position: layout::Position::new(0, 0),
             config,
+            scale: 1,
         }
     }
 
+    /// Draws the cursor over `cell`, the unscaled area of the cell under the cursor.
     pub fn draw<D, C>(
         &self,
-        display: &mut D,
-        #[cfg(feature = "framebuffer")] buffer: &crate::framebuffer::HeapBuffer<C>,
-        char_offset: geometry::Point,
-        char_w: i32,
-        char_h: i32,
+        display: &mut CursorTarget<'_, D>,
+        #[cfg(feature = "framebuffer")] buffer: &crate::framebuffer::Framebuffer<'_, C>,
+        #[cfg(feature = "framebuffer")] reversed: bool,
+        renderer: Option<&mut CursorFn<D>>,
+        cell: Rectangle,
     ) -> crate::error::Result<()>
     where
         D: DrawTarget<Color = C>,
+        D::Error: core::fmt::Debug + Send + Sync + 'static,
         C: PixelColor + Into<Rgb888> + From<Rgb888>,
     {
-        let top_left = geometry::Point::new(
-            self.position.x as i32 * char_w,
-            self.position.y as i32 * char_h,
-        ) + char_offset;
+        let origin = display.bounding_box().top_left;
+        let area = scale_rect(
+            &cell,
+            origin,
+            (self.scale, self.scale),
+            Rotation::Deg0,
+            cell.size,
+        );
+        let (top_left, char_w, char_h) = (
+            area.top_left,
+            area.size.width as i32,
+            area.size.height as i32,
+        );
 
         match self.config.style {
             #[cfg(feature = "framebuffer")]
-            CursorStyle::Inverse => Self::draw_inverse(display, buffer, top_left, char_w, char_h),
+            CursorStyle::Inverse => Self::draw_inverse(
+                display,
+                buffer,
+                top_left,
+                char_w,
+                char_h,
+                reversed && self.config.outline_on_reversed,
+                self.scale as i32,
+            ),
 
             #[cfg(not(feature = "framebuffer"))]
             CursorStyle::Inverse => {
@@ -83,6 +223,16 @@ impl Cursor {
                 Self::draw_line(display, top_left, char_h - 1, 0, char_w, 1, color)
             }
 
+            CursorStyle::Custom => match renderer {
+                Some(renderer) => {
+                    renderer(display, top_left, char_w, char_h).map_err(crate::error::Error::draw)
+                }
+                None => {
+                    let color: C = self.config.color.into();
+                    Self::draw_line(display, top_left, char_h - 1, 0, char_w, 1, color)
+                }
+            },
+
             CursorStyle::Underline => {
                 let color: C = self.config.color.into();
                 Self::draw_line(display, top_left, char_h - 1, 0, char_w, 1, color)
@@ -96,6 +246,23 @@ impl Cursor {
                 Self::draw_line(display, top_left, 0, char_w - 1, 1, char_h, color)
             }
 
+            CursorStyle::Block => {
+                let color: C = self.config.color.into();
+                Self::draw_line(display, top_left, 0, 0, char_w, char_h, color)
+            }
+
+            CursorStyle::Bar => {
+                let color: C = self.config.color.into();
+                let width = (char_w / 4).clamp(1, 2);
+                Self::draw_line(display, top_left, 0, 0, width, char_h, color)
+            }
+
+            CursorStyle::HalfBlock => {
+                let color: C = self.config.color.into();
+                let height = char_h / 2;
+                Self::draw_line(display, top_left, char_h - height, 0, char_w, height, color)
+            }
+
             CursorStyle::Japanese => {
                 let color: C = self.config.color.into();
                 let corner = (char_w / 2).max(2);
@@ -134,6 +301,7 @@ impl Cursor {
     ) -> crate::error::Result<()>
     where
         D: DrawTarget<Color = C>,
+        D::Error: core::fmt::Debug + Send + Sync + 'static,
         C: PixelColor,
     {
         display
@@ -144,35 +312,50 @@ impl Cursor {
                 ),
                 color,
             )
-            .map_err(|_| crate::error::Error::DrawError)
+            .map_err(crate::error::Error::draw)
     }
 
     #[cfg(feature = "framebuffer")]
     fn draw_inverse<D, C>(
         display: &mut D,
-        buffer: &crate::framebuffer::HeapBuffer<C>,
+        buffer: &crate::framebuffer::Framebuffer<'_, C>,
         top_left: geometry::Point,
         char_w: i32,
         char_h: i32,
+        outline: bool,
+        scale: i32,
     ) -> crate::error::Result<()>
     where
         D: DrawTarget<Color = C>,
+        D::Error: core::fmt::Debug + Send + Sync + 'static,
         C: PixelColor + Into<Rgb888> + From<Rgb888>,
     {
-        for y in top_left.y..top_left.y + char_h {
-            let row_rect = embedded_graphics::primitives::Rectangle::new(
-                geometry::Point::new(top_left.x, y),
-                geometry::Size::new(char_w as u32, 1),
+        let origin = display.bounding_box().top_left;
+        let mut invert = |x0: i32, y: i32, width: i32| {
+            let span = embedded_graphics::primitives::Rectangle::new(
+                geometry::Point::new(x0, y),
+                geometry::Size::new(width as u32, 1),
             );
             display
                 .fill_contiguous(
-                    &row_rect,
-                    (top_left.x..top_left.x + char_w).map(|x| {
-                        let rgb: Rgb888 = buffer.get_pixel(geometry::Point::new(x, y)).into();
+                    &span,
+                    (x0..x0 + width).map(|x| {
+                        let point = origin + (geometry::Point::new(x, y) - origin) / scale;
+                        let rgb: Rgb888 =
+                            buffer.get_pixel(point).map(Into::into).unwrap_or_default();
                         Rgb888::new(!rgb.r(), !rgb.g(), !rgb.b()).into()
                     }),
                 )
-                .map_err(|_| crate::error::Error::DrawError)?;
+                .map_err(crate::error::Error::draw)
+        };
+        let bottom = top_left.y + char_h - 1;
+        for y in top_left.y..=bottom {
+            if outline && y != top_left.y && y != bottom {
+                invert(top_left.x, y, 1)?;
+                invert(top_left.x + char_w - 1, y, 1)?;
+            } else {
+                invert(top_left.x, y, char_w)?;
+            }
         }
         Ok(())
     }
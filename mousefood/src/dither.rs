@@ -0,0 +1,163 @@
+//! Ordered dithering for monochrome and low color depth displays.
+
+use embedded_graphics::Pixel;
+use embedded_graphics::draw_target::DrawTarget;
+use embedded_graphics::geometry::{Dimensions, Point};
+use embedded_graphics::pixelcolor::raw::RawData;
+use embedded_graphics::pixelcolor::{Gray8, GrayColor, PixelColor, Rgb888, RgbColor};
+use embedded_graphics::primitives::Rectangle;
+
+/// Dithering applied to cell colors before they are drawn.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Dithering {
+    /// Colors are converted to the display color as-is.
+    #[default]
+    None,
+    /// Colors are reduced to black and white with a 4×4 Bayer matrix, so mid-tones
+    /// show up as patterns on monochrome displays such as `BinaryColor` panels.
+    Bayer4x4,
+}
+
+/// How cell colors are reduced to displays with fewer bits per channel, such as
+/// `Rgb565` or `Rgb666`.
+///
+/// Displays with 8 bits per channel or without RGB channels are not affected.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum ConversionQuality {
+    /// The low bits of each channel are dropped.
+    Truncate,
+    /// Each channel is rounded to the nearest level of the display.
+    #[default]
+    Round,
+    /// Each channel is truncated after adding an offset from a 4×4 Bayer matrix,
+    /// so colors between two levels show up as patterns and gradients stay smooth.
+    Dither,
+}
+
+const BAYER_4X4: [[u8; 4]; 4] = [[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]];
+
+/// Returns whether a pixel of `color` at `point` is set in the dithered output.
+fn bayer_on(color: Rgb888, point: Point) -> bool {
+    let threshold = BAYER_4X4[(point.y & 3) as usize][(point.x & 3) as usize] * 16 + 8;
+    Gray8::from(color).luma() > threshold
+}
+
+/// Returns the bits per red, green and blue channel of `C`, if it's an RGB color
+/// with less than 8 bits per channel.
+pub(crate) fn channel_bits<C: PixelColor>() -> Option<[u8; 3]> {
+    match C::Raw::BITS_PER_PIXEL {
+        15 => Some([5, 5, 5]),
+        16 => Some([5, 6, 5]),
+        18 => Some([6, 6, 6]),
+        _ => None,
+    }
+}
+
+/// Truncates each channel of `color` to `bits`, after adding the Bayer offset of
+/// `dither_point` if any.
+///
+/// The result converts to the truncated levels with the rounding conversion of
+/// embedded-graphics.
+pub(crate) fn truncate_channels(
+    color: Rgb888,
+    bits: [u8; 3],
+    dither_point: Option<Point>,
+) -> Rgb888 {
+    let threshold = dither_point.map_or(0, |point| {
+        BAYER_4X4[(point.y & 3) as usize][(point.x & 3) as usize] as u16
+    });
+    let channel = |value: u8, bits: u8| {
+        let shift = 8 - bits;
+        let max = (1u16 << bits) - 1;
+        let offset = (threshold << shift) / 16;
+        let level = ((value as u16 + offset) >> shift).min(max);
+        ((level * 255 + max / 2) / max) as u8
+    };
+    Rgb888::new(
+        channel(color.r(), bits[0]),
+        channel(color.g(), bits[1]),
+        channel(color.b(), bits[2]),
+    )
+}
+
+/// Draw target that dithers pixels drawn in the `on` and `off` key colors to the
+/// given foreground and background colors.
+///
+/// Without `channel_bits` the pixels are dithered to black and white, otherwise to
+/// the levels of the channels. Pixels of any other color are passed through
+/// unchanged.
+pub(crate) struct DitherTarget<'a, T: DrawTarget> {
+    pub(crate) target: &'a mut T,
+    pub(crate) channel_bits: Option<[u8; 3]>,
+    pub(crate) on: T::Color,
+    pub(crate) off: T::Color,
+    pub(crate) foreground: Rgb888,
+    pub(crate) background: Rgb888,
+}
+
+impl<T> Dimensions for DitherTarget<'_, T>
+where
+    T: DrawTarget,
+{
+    fn bounding_box(&self) -> Rectangle {
+        self.target.bounding_box()
+    }
+}
+
+impl<T, C> DrawTarget for DitherTarget<'_, T>
+where
+    T: DrawTarget<Color = C>,
+    C: PixelColor + From<Rgb888>,
+{
+    type Color = C;
+    type Error = T::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let (on, off) = (self.on, self.off);
+        let (foreground, background) = (self.foreground, self.background);
+        let channel_bits = self.channel_bits;
+        self.target
+            .draw_iter(pixels.into_iter().map(|Pixel(point, color)| {
+                let source = if color == on {
+                    foreground
+                } else if color == off {
+                    background
+                } else {
+                    return Pixel(point, color);
+                };
+                let dithered = match channel_bits {
+                    Some(bits) => truncate_channels(source, bits, Some(point)),
+                    None if bayer_on(source, point) => Rgb888::WHITE,
+                    None => Rgb888::BLACK,
+                };
+                Pixel(point, dithered.into())
+            }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mid_gray_is_checkerboard() {
+        let gray = Rgb888::new(128, 128, 128);
+        for y in 0..4 {
+            for x in 0..4 {
+                assert_eq!(bayer_on(gray, Point::new(x, y)), (x + y) % 2 == 0);
+            }
+        }
+    }
+
+    #[test]
+    fn truncation_drops_low_bits() {
+        let color = Rgb888::new(0xff, 0x07, 0x7f);
+        assert_eq!(
+            truncate_channels(color, [5, 6, 5], None),
+            Rgb888::new(0xff, 0x04, 0x7b)
+        );
+    }
+}
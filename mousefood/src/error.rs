@@ -5,12 +5,61 @@ pub type Result<T, E = Error> = core::result::Result<T, E>;
 
 /// Represents backend error.
 #[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
 pub enum Error {
     /// Drawing to the display failed.
-    #[error("drawing to DrawTarget failed")]
-    DrawError,
+    ///
+    /// Holds the error returned by the
+    /// [`DrawTarget`](embedded_graphics::draw_target::DrawTarget), e.g. a bus error
+    /// of the display driver.
+    #[error("drawing to DrawTarget failed: {0:?}")]
+    DrawError(alloc::boxed::Box<dyn core::fmt::Debug + Send + Sync>),
 
     /// Selected [`ClearType`](ratatui_core::backend::ClearType) is not supported by Mousefood.
+    ///
+    /// All current clear types are supported; this is kept for ones added in the future.
     #[error("ClearType::{0} is not supported by Mousefood")]
     ClearTypeUnsupported(alloc::string::String),
+
+    /// The operation isn't supported by the display or the enabled crate features.
+    #[error("{0} is not supported")]
+    Unsupported(&'static str),
+
+    /// A draw exceeded the configured maximum number of cells.
+    #[error("drawing more than {0} cells at once")]
+    CellLimit(usize),
+
+    /// The glyph data passed to [`font_from_raw`](crate::font_from_raw) doesn't
+    /// describe a font.
+    #[error("invalid font: {0}")]
+    InvalidFont(&'static str),
+
+    /// The framebuffer storage is too small for the display.
+    #[error("framebuffer storage holds {actual} pixels, but the display needs {expected}")]
+    BufferSize {
+        /// Number of pixels of the display.
+        expected: usize,
+        /// Number of pixels of the storage.
+        actual: usize,
+    },
+
+    /// The requested area is larger than the display.
+    #[error("{width}×{height} pixels don't fit on the {max_width}×{max_height} display")]
+    DisplaySize {
+        /// Requested width in pixels.
+        width: u32,
+        /// Requested height in pixels.
+        height: u32,
+        /// Width of the display in pixels.
+        max_width: u32,
+        /// Height of the display in pixels.
+        max_height: u32,
+    },
+}
+
+impl Error {
+    /// Wraps the error of a failed draw call.
+    pub(crate) fn draw<E: core::fmt::Debug + Send + Sync + 'static>(error: E) -> Self {
+        Error::DrawError(alloc::boxed::Box::new(error))
+    }
 }
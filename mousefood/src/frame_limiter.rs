@@ -0,0 +1,158 @@
+//! Capping the frame rate of the render loop.
+//!
+//! [`FrameLimiter`] spaces frames at least `1 / fps` apart, measured with a
+//! monotonic clock. Set it as
+//! [`EmbeddedBackendConfig::frame_limiter`](crate::EmbeddedBackendConfig::frame_limiter)
+//! to have [`flush`](ratatui_core::backend::Backend::flush) consult it, or call
+//! [`FrameLimiter::begin_frame`] from your own loop.
+//!
+//! With an [`embedded_hal::delay::DelayNs`] implementation, the limiter sleeps until
+//! the next frame is due. Without one, frames that come too early are skipped.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use core::time::Duration;
+//! use mousefood::FrameLimiter;
+//!
+//! // Usually backed by a hardware timer, e.g. `esp_hal::time::Instant::now()`.
+//! let clock = || Duration::ZERO;
+//! let mut limiter = FrameLimiter::new(30, clock);
+//!
+//! assert!(limiter.begin_frame());
+//! // The clock didn't advance, so the next frame isn't due yet.
+//! assert!(!limiter.begin_frame());
+//! ```
+
+use alloc::boxed::Box;
+use core::time::Duration;
+use embedded_hal::delay::DelayNs;
+
+/// Limits how often frames are started.
+pub struct FrameLimiter {
+    fps: u16,
+    interval: Duration,
+    clock: Box<dyn FnMut() -> Duration>,
+    delay: Option<Box<dyn DelayNs>>,
+    last_frame: Option<Duration>,
+}
+
+impl FrameLimiter {
+    /// Creates a limiter for `fps` frames per second, reading time from the
+    /// monotonic `clock`.
+    ///
+    /// An `fps` of 0 doesn't limit frames.
+    pub fn new(fps: u16, clock: impl FnMut() -> Duration + 'static) -> Self {
+        let interval = match fps {
+            0 => Duration::ZERO,
+            fps => Duration::from_secs(1) / u32::from(fps),
+        };
+        Self {
+            fps,
+            interval,
+            clock: Box::new(clock),
+            delay: None,
+            last_frame: None,
+        }
+    }
+
+    /// Sleeps with `delay` until the next frame is due, instead of skipping frames
+    /// that come too early.
+    pub fn with_delay(mut self, delay: impl DelayNs + 'static) -> Self {
+        self.delay = Some(Box::new(delay));
+        self
+    }
+
+    /// Returns the target frame rate.
+    pub fn fps(&self) -> u16 {
+        self.fps
+    }
+
+    /// Returns the time until the next frame is due.
+    pub fn remaining(&mut self) -> Duration {
+        match self.last_frame {
+            Some(last) => self
+                .interval
+                .saturating_sub((self.clock)().saturating_sub(last)),
+            None => Duration::ZERO,
+        }
+    }
+
+    /// Starts a frame if one is due and returns `true`.
+    ///
+    /// With a delay, waits for the next frame and always returns `true`. Without
+    /// one, returns `false` if the previous frame started less than `1 / fps` ago.
+    pub fn begin_frame(&mut self) -> bool {
+        let remaining = self.remaining();
+        if !remaining.is_zero() {
+            let Some(delay) = &mut self.delay else {
+                return false;
+            };
+            delay.delay_us(u32::try_from(remaining.as_micros()).unwrap_or(u32::MAX));
+        }
+        self.last_frame = Some((self.clock)());
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::rc::Rc;
+    use core::cell::Cell;
+
+    fn fake_clock() -> (Rc<Cell<Duration>>, impl FnMut() -> Duration + 'static) {
+        let now = Rc::new(Cell::new(Duration::ZERO));
+        let clock = {
+            let now = now.clone();
+            move || now.get()
+        };
+        (now, clock)
+    }
+
+    /// Advances the fake clock instead of sleeping.
+    struct FakeDelay(Rc<Cell<Duration>>);
+
+    impl DelayNs for FakeDelay {
+        fn delay_ns(&mut self, ns: u32) {
+            self.0.set(self.0.get() + Duration::from_nanos(ns.into()));
+        }
+    }
+
+    #[test]
+    fn skips_frames_within_interval() {
+        let (now, clock) = fake_clock();
+        let mut limiter = FrameLimiter::new(10, clock);
+
+        assert!(limiter.begin_frame());
+        now.set(Duration::from_millis(50));
+        assert!(!limiter.begin_frame());
+        assert_eq!(limiter.remaining(), Duration::from_millis(50));
+        now.set(Duration::from_millis(100));
+        assert!(limiter.begin_frame());
+        now.set(Duration::from_millis(199));
+        assert!(!limiter.begin_frame());
+    }
+
+    #[test]
+    fn delay_waits_for_next_frame() {
+        let (now, clock) = fake_clock();
+        let mut limiter = FrameLimiter::new(20, clock).with_delay(FakeDelay(now.clone()));
+
+        assert!(limiter.begin_frame());
+        now.set(Duration::from_millis(10));
+        assert!(limiter.begin_frame());
+        assert_eq!(now.get(), Duration::from_millis(50));
+        assert!(limiter.begin_frame());
+        assert_eq!(now.get(), Duration::from_millis(100));
+    }
+
+    #[test]
+    fn zero_fps_is_unlimited() {
+        let (_, clock) = fake_clock();
+        let mut limiter = FrameLimiter::new(0, clock);
+
+        assert!(limiter.begin_frame());
+        assert!(limiter.begin_frame());
+    }
+}
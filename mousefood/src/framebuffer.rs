@@ -1,48 +1,445 @@
-//! # Framebuffer on the heap
+//! # Framebuffer
 //!
 //! A framebuffer implementation for storing pixels in memory before rendering to a display.
 //!
-//! This module provides [`HeapBuffer`], a heap-allocated framebuffer that can be used
-//! with display drivers that don't provide their own internal framebuffer. This helps
-//! prevent screen tearing by allowing you to compose a complete frame in memory before
-//! sending it to the display.
+//! This module provides [`Framebuffer`], a framebuffer on the heap or in caller-provided
+//! storage that can be used with display drivers that don't provide their own internal
+//! framebuffer. This helps prevent screen tearing by allowing you to compose a complete
+//! frame in memory before sending it to the display.
 
 use alloc::{vec, vec::IntoIter, vec::Vec};
+use core::ops::{Deref, DerefMut};
 
 use crate::colors::{ColorTheme, TermColor, TermColorType};
 use embedded_graphics::Pixel;
 use embedded_graphics::draw_target::DrawTarget;
-use embedded_graphics::geometry::Dimensions;
-use embedded_graphics::pixelcolor::PixelColor;
-use embedded_graphics::primitives::Rectangle;
+use embedded_graphics::geometry::{Dimensions, Point};
+use embedded_graphics::pixelcolor::{PixelColor, Rgb888, RgbColor};
+use embedded_graphics::primitives::{PointsIter, Rectangle};
 use ratatui_core::style::Color;
 
-/// A heap-allocated framebuffer for storing pixels before rendering to a display.
+/// Pixel storage of a [`Framebuffer`].
+enum Storage<'a, C> {
+    /// Allocated by the buffer.
+    Heap(Vec<C>),
+    /// Provided by the user, of which the first `len` pixels are used.
+    Borrowed(&'a mut [C], usize),
+}
+
+impl<C: Copy> Storage<'_, C> {
+    /// Returns whether the storage can hold `len` pixels.
+    fn can_hold(&self, len: usize) -> bool {
+        match self {
+            Storage::Heap(_) => true,
+            Storage::Borrowed(pixels, _) => pixels.len() >= len,
+        }
+    }
+
+    /// Resizes the storage to `len` pixels of `color`. The storage must be able to
+    /// hold them.
+    fn resize(&mut self, len: usize, color: C) {
+        match self {
+            Storage::Heap(pixels) => {
+                pixels.clear();
+                pixels.resize(len, color);
+            }
+            Storage::Borrowed(pixels, used) => {
+                *used = len;
+                pixels[..len].fill(color);
+            }
+        }
+    }
+}
+
+impl<C> Deref for Storage<'_, C> {
+    type Target = [C];
+
+    fn deref(&self) -> &[C] {
+        match self {
+            Storage::Heap(pixels) => pixels,
+            Storage::Borrowed(pixels, len) => &pixels[..*len],
+        }
+    }
+}
+
+impl<C> DerefMut for Storage<'_, C> {
+    fn deref_mut(&mut self) -> &mut [C] {
+        match self {
+            Storage::Heap(pixels) => pixels,
+            Storage::Borrowed(pixels, len) => &mut pixels[..*len],
+        }
+    }
+}
+
+/// Pixel storage for a framebuffer that doesn't allocate.
 ///
-/// `HeapBuffer` provides a memory-based framebuffer that can be used with display drivers
+/// Holds `N` pixels in an array owned by the caller, e.g. in a `static`. `N` must be
+/// at least the `width × height` of the display, see
+/// [`EmbeddedBackend::with_buffer`](crate::EmbeddedBackend::with_buffer).
+pub struct StaticBuffer<C, const N: usize>([C; N]);
+
+impl<C: Copy, const N: usize> StaticBuffer<C, N> {
+    /// Creates a buffer with all pixels set to `color`.
+    pub const fn new(color: C) -> Self {
+        Self([color; N])
+    }
+}
+
+impl<C, const N: usize> Deref for StaticBuffer<C, N> {
+    type Target = [C];
+
+    fn deref(&self) -> &[C] {
+        &self.0
+    }
+}
+
+impl<C, const N: usize> DerefMut for StaticBuffer<C, N> {
+    fn deref_mut(&mut self) -> &mut [C] {
+        &mut self.0
+    }
+}
+
+/// A framebuffer for storing pixels before rendering to a display.
+///
+/// `Framebuffer` provides a memory-based framebuffer that can be used with display drivers
 /// that don't have their own internal framebuffer. This helps prevent screen tearing by
 /// allowing you to compose a complete frame in memory before sending it to the display.
 ///
 /// The framebuffer is generic over any pixel color type that implements [`PixelColor`]
-/// and [`Copy`]. It stores pixels in a contiguous `Vec<C>` arranged in row-major order.
+/// and [`Copy`]. It stores pixels contiguously in row-major order, in a `Vec<C>` or in a
+/// slice provided by the caller, e.g. a [`StaticBuffer`].
 ///
 /// # Type Parameters
 ///
 /// * `C` - The pixel color type. Must implement [`PixelColor`] and [`Copy`].
-pub struct HeapBuffer<C: PixelColor + Copy> {
-    data: Vec<C>,
+///
+/// # Off-screen rendering
+///
+/// A `Framebuffer` is a [`DrawTarget`] itself, so a backend can render into one
+/// without a physical display, e.g. to composite the terminal into a larger scene or
+/// for golden-image tests. The pixels can be read back with [`get_pixel`](Self::get_pixel)
+/// once the terminal is flushed.
+///
+/// ```
+/// use mousefood::embedded_graphics::geometry::{Point, Size};
+/// use mousefood::embedded_graphics::primitives::Rectangle;
+/// use mousefood::prelude::*;
+/// use mousefood::Framebuffer;
+/// use ratatui::{Terminal, widgets::Paragraph};
+///
+/// let area = Rectangle::new(Point::zero(), Size::new(64, 32));
+/// let mut buffer = Framebuffer::<Rgb888>::new(area, ColorTheme::default());
+/// let backend = EmbeddedBackend::from_display(&mut buffer);
+/// let mut terminal = Terminal::new(backend).unwrap();
+/// terminal.draw(|frame| frame.render_widget(Paragraph::new("Hi"), frame.area())).unwrap();
+/// drop(terminal);
+///
+/// assert!(buffer.get_pixel(Point::new(1, 1)).is_some());
+/// ```
+pub struct Framebuffer<'a, C: PixelColor + Copy> {
+    data: Storage<'a, C>,
     bounding_box: Rectangle,
+    dirty: Option<Rectangle>,
+    /// Front buffer holding the pixels last transferred to the display, when double
+    /// buffering. `data` is then the back buffer, and the two are swapped after
+    /// each flush.
+    front: Option<Storage<'a, C>>,
+    /// Region where the display may differ from `front`.
+    stale: Option<Rectangle>,
+    /// Number of drawing calls made to the buffer, for tests that count them.
+    #[cfg(test)]
+    pub(crate) draw_calls: usize,
 }
 
-impl<C: PixelColor + Copy> HeapBuffer<C> {
-    /// Returns the color of the pixel at the given point.
-    pub fn get_pixel(&self, point: embedded_graphics::geometry::Point) -> C {
+impl<'a, C: PixelColor + Copy> Framebuffer<'a, C> {
+    /// Returns the color of the pixel at the given point, or `None` if the point is
+    /// outside the buffer's bounding box.
+    ///
+    /// Points are in display coordinates, i.e. relative to the origin of the display
+    /// rather than to the top-left corner of the bounding box.
+    pub fn get_pixel(&self, point: embedded_graphics::geometry::Point) -> Option<C> {
+        if !self.bounding_box.contains(point) {
+            return None;
+        }
+        let point = point - self.bounding_box.top_left;
         let idx = (point.y * self.bounding_box.size.width as i32 + point.x) as usize;
-        self.data[idx]
+        Some(self.data[idx])
+    }
+
+    /// Returns all pixels with their position, in row-major order.
+    pub fn iter_pixels(&self) -> impl Iterator<Item = (Point, C)> + '_ {
+        self.bounding_box.points().zip(self.data.iter().copied())
+    }
+
+    /// Returns the number of pixels the storage can hold, in both buffers if double
+    /// buffering.
+    pub(crate) fn capacity(&self) -> usize {
+        let capacity = |storage: &Storage<'a, C>| match storage {
+            Storage::Heap(pixels) => pixels.capacity(),
+            Storage::Borrowed(pixels, _) => pixels.len(),
+        };
+        let front = self.front.as_ref().map_or(usize::MAX, capacity);
+        capacity(&self.data).min(front)
+    }
+
+    /// Returns the size of the pixel storage in bytes, including the front buffer
+    /// if double buffering.
+    pub fn size_bytes(&self) -> usize {
+        let front = self.front.as_ref().map_or(0, |front| front.len());
+        (self.data.len() + front) * core::mem::size_of::<C>()
+    }
+
+    /// Overwrites the buffer contents with `pixels`, in row-major order.
+    ///
+    /// If the iterator yields fewer pixels than the buffer holds, the remaining
+    /// pixels are left untouched. Extra pixels are ignored.
+    pub fn fill_from<I>(&mut self, pixels: I)
+    where
+        I: IntoIterator<Item = C>,
+    {
+        for (dst, src) in self.data.iter_mut().zip(pixels) {
+            *dst = src;
+        }
+        self.mark_dirty(self.bounding_box);
+    }
+
+    /// Adds `area` to the region changed since the last [`take_dirty`](Self::take_dirty).
+    pub(crate) fn mark_dirty(&mut self, area: Rectangle) {
+        self.dirty = union(self.dirty, area.intersection(&self.bounding_box));
+    }
+
+    /// Adds a front buffer holding the pixels transferred to the display, so that
+    /// [`changed_rows`](Self::changed_rows) can skip rows the display already shows
+    /// and [`swap`](Self::swap) can exchange it with the back buffer after a flush.
+    ///
+    /// The front buffer is stored in `pixels`, or allocated on the heap if `None`.
+    /// Returns `false` if `pixels` is smaller than the framebuffer.
+    pub(crate) fn set_front(&mut self, pixels: Option<&'a mut [C]>) -> bool {
+        let len = self.data.len();
+        let mut front = match pixels {
+            Some(pixels) if pixels.len() < len => return false,
+            Some(pixels) => Storage::Borrowed(pixels, len),
+            None => Storage::Heap(self.data.to_vec()),
+        };
+        front.copy_from_slice(&self.data);
+        self.front = Some(front);
+        self.stale = Some(self.bounding_box);
+        true
+    }
+
+    /// Marks `area` as changed on the display itself, e.g. by drawing to it directly,
+    /// so it's transferred on the next flush even if the pixels didn't change.
+    pub(crate) fn mark_stale(&mut self, area: Rectangle) {
+        let area = area.intersection(&self.bounding_box);
+        self.stale = union(self.stale, area);
+        self.mark_dirty(area);
+    }
+
+    /// Shrinks `area` to the rows that differ between the back and front buffers.
+    ///
+    /// Returns `None` if the display already shows all pixels of `area`. Without a
+    /// front buffer, `area` is returned as is.
+    pub(crate) fn changed_rows(&self, area: Rectangle) -> Option<Rectangle> {
+        let Some(front) = &self.front else {
+            return Some(area);
+        };
+        let area = area.intersection(&self.bounding_box);
+        let width = self.bounding_box.size.width as usize;
+        let offset = area.top_left - self.bounding_box.top_left;
+        let x0 = offset.x as usize;
+        let x1 = x0 + area.size.width as usize;
+        let stale_rows = self
+            .stale
+            .map(|stale| stale.intersection(&area))
+            .filter(|stale| !stale.is_zero_sized())
+            .map_or(0..0, |stale| stale.rows());
+        let changed = |y: u32| {
+            let row = (offset.y as u32 + y) as usize * width;
+            stale_rows.contains(&(area.top_left.y + y as i32))
+                || self.data[row + x0..row + x1] != front[row + x0..row + x1]
+        };
+        let first = (0..area.size.height).find(|&y| changed(y))?;
+        let last = (first..area.size.height).rfind(|&y| changed(y))?;
+        Some(Rectangle::new(
+            area.top_left + Point::new(0, first as i32),
+            embedded_graphics::geometry::Size::new(area.size.width, last - first + 1),
+        ))
+    }
+
+    /// Records that the display shows the pixels inside `area`, by copying them to
+    /// the front buffer.
+    ///
+    /// Used when only part of a frame was transferred, e.g. the rows exposed by a
+    /// hardware scroll; whole frames are [swapped](Self::swap) instead.
+    pub(crate) fn present(&mut self, area: Rectangle) {
+        let Some(front) = &mut self.front else {
+            return;
+        };
+        copy_rows(front, &self.data, self.bounding_box, area);
+        self.clear_stale(area);
+    }
+
+    /// Swaps the back and front buffers after the rows of `area` were transferred
+    /// to the display.
+    ///
+    /// The transferred pixels become the front buffer and aren't written again
+    /// until the next flush, so the display may keep reading them, e.g. by DMA,
+    /// while the next frame is drawn. Outside `area` both buffers already match,
+    /// so only `area` is copied back to bring the new back buffer up to date.
+    pub(crate) fn swap(&mut self, area: Rectangle) {
+        let Some(front) = &mut self.front else {
+            return;
+        };
+        core::mem::swap(&mut self.data, front);
+        copy_rows(&mut self.data, front, self.bounding_box, area);
+        self.clear_stale(area);
+    }
+
+    /// Forgets the stale region if `area` covers it.
+    fn clear_stale(&mut self, area: Rectangle) {
+        if self
+            .stale
+            .is_some_and(|stale| area.intersection(&stale) == stale)
+        {
+            self.stale = None;
+        }
+    }
+
+    /// Returns whether any pixels changed since the last [`take_dirty`](Self::take_dirty).
+    pub(crate) fn is_dirty(&self) -> bool {
+        self.dirty.is_some()
+    }
+
+    /// Returns the bounding rectangle of all pixels changed since the last call.
+    pub(crate) fn take_dirty(&mut self) -> Option<Rectangle> {
+        self.dirty.take()
+    }
+
+    /// Returns the pixels inside `area`, in row-major order.
+    ///
+    /// `area` must lie within the bounding box of the buffer.
+    pub(crate) fn region(&self, area: Rectangle) -> impl Iterator<Item = C> + '_ {
+        let width = self.bounding_box.size.width as usize;
+        let offset = area.top_left - self.bounding_box.top_left;
+        let x0 = offset.x as usize;
+        let x1 = x0 + area.size.width as usize;
+        let y0 = offset.y as usize;
+        let y1 = y0 + area.size.height as usize;
+        (y0..y1).flat_map(move |y| self.data[y * width + x0..y * width + x1].iter().copied())
+    }
+
+    /// Returns the rows of pixels inside `area` with the area each covers, from top
+    /// to bottom.
+    ///
+    /// `area` must lie within the bounding box of the buffer.
+    pub(crate) fn rows(&self, area: Rectangle) -> impl Iterator<Item = (Rectangle, &[C])> + '_ {
+        let width = self.bounding_box.size.width as usize;
+        let offset = area.top_left - self.bounding_box.top_left;
+        let x0 = offset.x as usize;
+        let x1 = x0 + area.size.width as usize;
+        (0..area.size.height).map(move |row| {
+            let y = offset.y as usize + row as usize;
+            let rect = Rectangle::new(
+                area.top_left + embedded_graphics::geometry::Point::new(0, row as i32),
+                embedded_graphics::geometry::Size::new(area.size.width, 1),
+            );
+            (rect, &self.data[y * width + x0..y * width + x1])
+        })
+    }
+
+    /// Moves the pixels inside `area` up by `dy` rows, filling the vacated rows with `fill`.
+    pub(crate) fn scroll_up(&mut self, area: Rectangle, dy: u32, fill: C) {
+        let area = area.intersection(&self.bounding_box);
+        let area = Rectangle::new(area.top_left - self.bounding_box.top_left, area.size);
+        scroll_rows(&mut self.data, self.bounding_box.size.width, area, dy, fill);
+    }
+
+    /// Moves the pixels inside `area` down by `dy` rows, filling the rows exposed at the
+    /// top with `fill`.
+    pub(crate) fn scroll_down(&mut self, area: Rectangle, dy: u32, fill: C) {
+        let area = area.intersection(&self.bounding_box);
+        let area = Rectangle::new(area.top_left - self.bounding_box.top_left, area.size);
+        scroll_rows_down(&mut self.data, self.bounding_box.size.width, area, dy, fill);
+    }
+
+    /// Moves the front buffer like [`scroll_up`](Self::scroll_up), for displays
+    /// that scrolled their content in hardware.
+    pub(crate) fn scroll_front_up(&mut self, area: Rectangle, dy: u32, fill: C) {
+        let area = area.intersection(&self.bounding_box);
+        let area = Rectangle::new(area.top_left - self.bounding_box.top_left, area.size);
+        if let Some(front) = &mut self.front {
+            scroll_rows(front, self.bounding_box.size.width, area, dy, fill);
+        }
+    }
+}
+
+/// Copies the pixels inside `area` from `src` to `dst`, both covering
+/// `bounding_box` in row-major order.
+fn copy_rows<C: Copy>(dst: &mut [C], src: &[C], bounding_box: Rectangle, area: Rectangle) {
+    let area = area.intersection(&bounding_box);
+    let width = bounding_box.size.width as usize;
+    let offset = area.top_left - bounding_box.top_left;
+    let x0 = offset.x as usize;
+    let x1 = x0 + area.size.width as usize;
+    for y in offset.y as usize..offset.y as usize + area.size.height as usize {
+        dst[y * width + x0..y * width + x1].copy_from_slice(&src[y * width + x0..y * width + x1]);
     }
 }
 
-impl<C: PixelColor + for<'a> From<TermColor<'a>>> HeapBuffer<C> {
+/// Moves the pixels of `pixels` inside `area` up by `dy` rows, filling the vacated
+/// rows with `fill`.
+fn scroll_rows<C: Copy>(pixels: &mut [C], width: u32, area: Rectangle, dy: u32, fill: C) {
+    let width = width as usize;
+    let x0 = area.top_left.x as usize;
+    let x1 = x0 + area.size.width as usize;
+    let y0 = area.top_left.y as usize;
+    let y1 = y0 + area.size.height as usize;
+    let split = y1 - (dy as usize).min(y1 - y0);
+    for y in y0..split {
+        let src = (y + y1 - split) * width;
+        pixels.copy_within(src + x0..src + x1, y * width + x0);
+    }
+    for y in split..y1 {
+        pixels[y * width + x0..y * width + x1].fill(fill);
+    }
+}
+
+/// Moves the pixels of `pixels` inside `area` down by `dy` rows, filling the vacated
+/// rows with `fill`.
+fn scroll_rows_down<C: Copy>(pixels: &mut [C], width: u32, area: Rectangle, dy: u32, fill: C) {
+    let width = width as usize;
+    let x0 = area.top_left.x as usize;
+    let x1 = x0 + area.size.width as usize;
+    let y0 = area.top_left.y as usize;
+    let y1 = y0 + area.size.height as usize;
+    let split = y0 + (dy as usize).min(y1 - y0);
+    for y in (split..y1).rev() {
+        let src = (y - (split - y0)) * width;
+        pixels.copy_within(src + x0..src + x1, y * width + x0);
+    }
+    for y in y0..split {
+        pixels[y * width + x0..y * width + x1].fill(fill);
+    }
+}
+
+/// Returns the bounding rectangle of `region` and `area`.
+pub(crate) fn union(region: Option<Rectangle>, area: Rectangle) -> Option<Rectangle> {
+    if area.is_zero_sized() {
+        return region;
+    }
+    let Some(region) = region else {
+        return Some(area);
+    };
+    let (Some(a), Some(b)) = (region.bottom_right(), area.bottom_right()) else {
+        return Some(region);
+    };
+    Some(Rectangle::with_corners(
+        region.top_left.component_min(area.top_left),
+        a.component_max(b),
+    ))
+}
+
+impl<'a, C: PixelColor + for<'t> From<TermColor<'t>>> Framebuffer<'a, C> {
     /// Creates a new framebuffer with the specified dimensions.
     ///
     /// The framebuffer is initialized with a background color derived from [`Color::Reset`].
@@ -55,34 +452,133 @@ impl<C: PixelColor + for<'a> From<TermColor<'a>>> HeapBuffer<C> {
     ///
     /// # Returns
     ///
-    /// A new [`HeapBuffer`] instance with the specified dimensions, initialized with
+    /// A new [`Framebuffer`] instance with the specified dimensions, initialized with
     /// the default background color.
     ///
     /// # Memory Usage
     ///
     /// This method allocates `width × height × sizeof(C)` bytes of memory on the heap,
     /// where `C` is the pixel color type.
-    pub fn new(bounding_box: Rectangle, color_theme: ColorTheme) -> HeapBuffer<C> {
+    pub fn new(bounding_box: Rectangle, color_theme: ColorTheme) -> Framebuffer<'a, C> {
         Self {
-            data: vec![
-                TermColor::new(Color::Reset, TermColorType::Background, &color_theme).into();
-                (bounding_box.size.width * bounding_box.size.height) as usize
-            ],
+            data: Storage::Heap(vec![background(&color_theme); pixel_count(bounding_box)]),
+            bounding_box,
+            dirty: Some(bounding_box),
+            front: None,
+            stale: None,
+            #[cfg(test)]
+            draw_calls: 0,
+        }
+    }
+
+    /// Creates a framebuffer that stores its pixels in `pixels` instead of the heap.
+    ///
+    /// Returns `None` if `pixels` holds fewer than `width × height` pixels. Extra
+    /// pixels are left unused.
+    pub fn from_slice(
+        bounding_box: Rectangle,
+        pixels: &'a mut [C],
+        color_theme: ColorTheme,
+    ) -> Option<Framebuffer<'a, C>> {
+        let len = pixel_count(bounding_box);
+        let pixels = pixels.get_mut(..len)?;
+        pixels.fill(background(&color_theme));
+        Some(Self {
+            data: Storage::Borrowed(pixels, len),
             bounding_box,
+            dirty: Some(bounding_box),
+            front: None,
+            stale: None,
+            #[cfg(test)]
+            draw_calls: 0,
+        })
+    }
+
+    /// Changes the size of the framebuffer and clears it, keeping its storage.
+    ///
+    /// Returns `false` if the storage passed to [`from_slice`](Self::from_slice) is
+    /// too small for the new size.
+    pub(crate) fn reshape(&mut self, bounding_box: Rectangle, color_theme: ColorTheme) -> bool {
+        let len = pixel_count(bounding_box);
+        if !self.data.can_hold(len) || self.front.as_ref().is_some_and(|s| !s.can_hold(len)) {
+            return false;
         }
+        let color = background(&color_theme);
+        self.data.resize(len, color);
+        self.bounding_box = bounding_box;
+        self.dirty = Some(bounding_box);
+        if let Some(front) = &mut self.front {
+            front.resize(len, color);
+            self.stale = Some(bounding_box);
+        }
+        true
     }
 }
 
-impl<C: PixelColor> IntoIterator for HeapBuffer<C> {
+impl<C: PixelColor + Into<Rgb888>> Framebuffer<'_, C> {
+    /// Encodes the framebuffer contents as a 24-bit BMP image.
+    ///
+    /// Useful for saving what the display shows, e.g. for documentation or to compare
+    /// rendering against golden images in tests.
+    pub fn to_bmp(&self) -> Vec<u8> {
+        const HEADER_SIZE: u32 = 14 + 40;
+        let width = self.bounding_box.size.width;
+        let height = self.bounding_box.size.height;
+        // Rows are padded to a multiple of 4 bytes.
+        let row_size = (width * 3).next_multiple_of(4);
+        let image_size = row_size * height;
+
+        let mut bmp = Vec::with_capacity((HEADER_SIZE + image_size) as usize);
+        // File header
+        bmp.extend_from_slice(b"BM");
+        bmp.extend_from_slice(&(HEADER_SIZE + image_size).to_le_bytes());
+        bmp.extend_from_slice(&0u32.to_le_bytes());
+        bmp.extend_from_slice(&HEADER_SIZE.to_le_bytes());
+        // Info header
+        bmp.extend_from_slice(&40u32.to_le_bytes());
+        bmp.extend_from_slice(&(width as i32).to_le_bytes());
+        bmp.extend_from_slice(&(height as i32).to_le_bytes());
+        bmp.extend_from_slice(&1u16.to_le_bytes());
+        bmp.extend_from_slice(&24u16.to_le_bytes());
+        bmp.extend_from_slice(&0u32.to_le_bytes());
+        bmp.extend_from_slice(&image_size.to_le_bytes());
+        bmp.extend_from_slice(&2835i32.to_le_bytes());
+        bmp.extend_from_slice(&2835i32.to_le_bytes());
+        bmp.extend_from_slice(&0u32.to_le_bytes());
+        bmp.extend_from_slice(&0u32.to_le_bytes());
+        // Pixel rows, bottom to top, in BGR order
+        for row in self.data.chunks_exact(width as usize).rev() {
+            for &color in row {
+                let color: Rgb888 = color.into();
+                bmp.extend_from_slice(&[color.b(), color.g(), color.r()]);
+            }
+            bmp.resize(bmp.len() + (row_size - width * 3) as usize, 0);
+        }
+        bmp
+    }
+}
+
+fn pixel_count(bounding_box: Rectangle) -> usize {
+    (bounding_box.size.width * bounding_box.size.height) as usize
+}
+
+fn background<C: for<'t> From<TermColor<'t>>>(color_theme: &ColorTheme) -> C {
+    TermColor::new(Color::Reset, TermColorType::Background, color_theme).into()
+}
+
+impl<C: PixelColor> IntoIterator for Framebuffer<'_, C> {
     type Item = C;
     type IntoIter = IntoIter<Self::Item>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.data.into_iter()
+        match self.data {
+            Storage::Heap(pixels) => pixels.into_iter(),
+            Storage::Borrowed(pixels, len) => Vec::from(&pixels[..len]).into_iter(),
+        }
     }
 }
 
-impl<'a, C: PixelColor + Copy> IntoIterator for &'a HeapBuffer<C> {
+impl<'a, C: PixelColor + Copy> IntoIterator for &'a Framebuffer<'_, C> {
     type Item = C;
     type IntoIter = core::iter::Copied<core::slice::Iter<'a, C>>;
 
@@ -91,13 +587,13 @@ impl<'a, C: PixelColor + Copy> IntoIterator for &'a HeapBuffer<C> {
     }
 }
 
-impl<C: PixelColor> Dimensions for HeapBuffer<C> {
+impl<C: PixelColor> Dimensions for Framebuffer<'_, C> {
     fn bounding_box(&self) -> Rectangle {
         self.bounding_box
     }
 }
 
-impl<C: PixelColor> DrawTarget for HeapBuffer<C> {
+impl<C: PixelColor> DrawTarget for Framebuffer<'_, C> {
     type Color = C;
     type Error = core::convert::Infallible;
 
@@ -105,13 +601,65 @@ impl<C: PixelColor> DrawTarget for HeapBuffer<C> {
     where
         I: IntoIterator<Item = Pixel<Self::Color>>,
     {
+        #[cfg(test)]
+        {
+            self.draw_calls += 1;
+        }
         let idx_end = self.data.len() - 1;
+        let top_left = self.bounding_box.top_left;
+        let mut changed: Option<(Point, Point)> = None;
         for Pixel(point, color) in pixels {
-            let idx = point.y as usize * self.bounding_box.size.width as usize + point.x as usize;
-            self.data[idx.clamp(0, idx_end)] = color;
+            let offset = point - top_left;
+            let idx = offset.y as usize * self.bounding_box.size.width as usize + offset.x as usize;
+            let idx = idx.clamp(0, idx_end);
+            // Pixels that keep their color don't need to be transferred again.
+            if self.data[idx] == color {
+                continue;
+            }
+            changed = Some(match changed {
+                None => (point, point),
+                Some((min, max)) => (min.component_min(point), max.component_max(point)),
+            });
+            self.data[idx] = color;
+        }
+        if let Some((min, max)) = changed {
+            self.mark_dirty(Rectangle::with_corners(min, max));
         }
         Ok(())
     }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        #[cfg(test)]
+        {
+            self.draw_calls += 1;
+        }
+        let area = area.intersection(&self.bounding_box);
+        let width = self.bounding_box.size.width as usize;
+        let offset = area.top_left - self.bounding_box.top_left;
+        let x0 = offset.x as usize;
+        let x1 = x0 + area.size.width as usize;
+        let mut changed = None;
+        for y in offset.y as usize..offset.y as usize + area.size.height as usize {
+            let row = &mut self.data[y * width + x0..y * width + x1];
+            if row.iter().any(|&pixel| pixel != color) {
+                row.fill(color);
+                changed = Some(changed.map_or((y, y), |(first, _)| (first, y)));
+            }
+        }
+        if let Some((first, last)) = changed {
+            self.mark_dirty(Rectangle::new(
+                Point::new(area.top_left.x, self.bounding_box.top_left.y + first as i32),
+                embedded_graphics::geometry::Size::new(area.size.width, (last - first + 1) as u32),
+            ));
+        }
+        Ok(())
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        self.data.fill(color);
+        self.mark_dirty(self.bounding_box);
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -122,7 +670,6 @@ mod tests {
     use embedded_graphics::mock_display::MockDisplay;
     use embedded_graphics::mono_font::MonoTextStyle;
     use embedded_graphics::mono_font::ascii::FONT_4X6;
-    use embedded_graphics::pixelcolor::{Rgb888, RgbColor};
     use embedded_graphics::prelude::*;
     use embedded_graphics::text::Text;
 
@@ -132,8 +679,8 @@ mod tests {
     }
 
     #[fixture]
-    fn heap_buffer() -> HeapBuffer<Rgb888> {
-        HeapBuffer::new(
+    fn heap_buffer() -> Framebuffer<'static, Rgb888> {
+        Framebuffer::new(
             Rectangle::new(Point::zero(), Size::new(16, 8)),
             ColorTheme::default(),
         )
@@ -163,7 +710,7 @@ mod tests {
     #[rstest]
     fn test_heap_buffer(
         mut display: MockDisplay<Rgb888>,
-        mut heap_buffer: HeapBuffer<Rgb888>,
+        mut heap_buffer: Framebuffer<'static, Rgb888>,
         #[from(test_text)] (text, expected): (Text<MonoTextStyle<Rgb888>>, &[&str]),
     ) {
         text.draw(&mut heap_buffer).unwrap();
@@ -178,7 +725,7 @@ mod tests {
     #[rstest]
     fn test_heap_buffer_as_ref(
         mut display: MockDisplay<Rgb888>,
-        mut heap_buffer: HeapBuffer<Rgb888>,
+        mut heap_buffer: Framebuffer<'static, Rgb888>,
         #[from(test_text)] (text, expected): (Text<MonoTextStyle<Rgb888>>, &[&str]),
     ) {
         text.draw(&mut heap_buffer).unwrap();
@@ -189,4 +736,20 @@ mod tests {
 
         display.assert_pattern(expected);
     }
+
+    #[rstest]
+    fn iter_pixels_yields_every_pixel(mut heap_buffer: Framebuffer<'static, Rgb888>) {
+        Pixel(Point::new(3, 2), Rgb888::RED)
+            .draw(&mut heap_buffer)
+            .unwrap();
+
+        let pixels: Vec<_> = heap_buffer.iter_pixels().collect();
+        assert_eq!(pixels.len(), 16 * 8);
+        assert_eq!(pixels[2 * 16 + 3], (Point::new(3, 2), Rgb888::RED));
+        for (point, color) in pixels {
+            assert_eq!(heap_buffer.get_pixel(point), Some(color));
+        }
+        assert_eq!(heap_buffer.get_pixel(Point::new(16, 0)), None);
+        assert_eq!(heap_buffer.get_pixel(Point::new(0, -1)), None);
+    }
 }
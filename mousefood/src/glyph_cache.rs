@@ -0,0 +1,149 @@
+//! Cache of rendered glyph pixels.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use embedded_graphics::Pixel;
+use embedded_graphics::draw_target::DrawTarget;
+use embedded_graphics::geometry::{Dimensions, Point};
+use embedded_graphics::pixelcolor::PixelColor;
+use embedded_graphics::primitives::Rectangle;
+use ratatui_core::style::Modifier;
+
+/// Everything besides the symbol that changes how a cell is rendered.
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) struct GlyphStyle<C> {
+    pub(crate) fg: C,
+    pub(crate) bg: C,
+    pub(crate) underline: Option<C>,
+    pub(crate) modifier: Modifier,
+}
+
+struct Entry<C> {
+    symbol: String,
+    style: GlyphStyle<C>,
+    /// Pixels of the glyph's bounding box in row-major order, or `None` if the glyph
+    /// doesn't cover exactly its bounding box and has to be drawn with the font.
+    pixels: Option<Vec<C>>,
+}
+
+/// Least recently used cache of glyph pixels, keyed by symbol and style.
+pub(crate) struct GlyphCache<C> {
+    capacity: usize,
+    /// Ordered from least to most recently used.
+    entries: Vec<Entry<C>>,
+    hits: u32,
+}
+
+/// Result of a cache lookup.
+pub(crate) enum Lookup<'a, C> {
+    /// The pixels of the glyph's bounding box.
+    Hit(&'a [C]),
+    /// The glyph is known not to be cacheable.
+    Uncacheable,
+    /// The glyph hasn't been rendered yet, or was evicted.
+    Miss,
+}
+
+impl<C: PixelColor> GlyphCache<C> {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: Vec::new(),
+            hits: 0,
+        }
+    }
+
+    /// Number of glyphs drawn from the cache.
+    pub(crate) fn hits(&self) -> u32 {
+        self.hits
+    }
+
+    pub(crate) fn get(&mut self, symbol: &str, style: &GlyphStyle<C>) -> Lookup<'_, C> {
+        if self.capacity == 0 {
+            return Lookup::Uncacheable;
+        }
+        let Some(index) = self
+            .entries
+            .iter()
+            .position(|entry| entry.symbol == symbol && entry.style == *style)
+        else {
+            return Lookup::Miss;
+        };
+        let entry = self.entries.remove(index);
+        self.entries.push(entry);
+        match &self.entries[self.entries.len() - 1].pixels {
+            Some(pixels) => {
+                self.hits = self.hits.wrapping_add(1);
+                Lookup::Hit(pixels)
+            }
+            None => Lookup::Uncacheable,
+        }
+    }
+
+    pub(crate) fn insert(&mut self, symbol: &str, style: GlyphStyle<C>, pixels: Option<Vec<C>>) {
+        if self.entries.len() >= self.capacity {
+            self.entries.remove(0);
+        }
+        self.entries.push(Entry {
+            symbol: symbol.into(),
+            style,
+            pixels,
+        });
+    }
+}
+
+/// Draw target that passes pixels through and records the ones inside `area`.
+pub(crate) struct GlyphRecorder<'a, T: DrawTarget> {
+    target: &'a mut T,
+    area: Rectangle,
+    pixels: Vec<Option<T::Color>>,
+    outside: bool,
+}
+
+impl<'a, T: DrawTarget> GlyphRecorder<'a, T> {
+    pub(crate) fn new(target: &'a mut T, area: Rectangle) -> Self {
+        let len = area.size.width as usize * area.size.height as usize;
+        Self {
+            target,
+            area,
+            pixels: alloc::vec![None; len],
+            outside: false,
+        }
+    }
+
+    /// Returns the recorded pixels if every pixel of `area`, and no pixel outside of
+    /// it, was drawn.
+    pub(crate) fn finish(self) -> Option<Vec<T::Color>> {
+        if self.outside {
+            return None;
+        }
+        self.pixels.into_iter().collect()
+    }
+}
+
+impl<T: DrawTarget> Dimensions for GlyphRecorder<'_, T> {
+    fn bounding_box(&self) -> Rectangle {
+        self.target.bounding_box()
+    }
+}
+
+impl<T: DrawTarget> DrawTarget for GlyphRecorder<'_, T> {
+    type Color = T::Color;
+    type Error = T::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let (area, recorded, outside) = (self.area, &mut self.pixels, &mut self.outside);
+        self.target
+            .draw_iter(pixels.into_iter().inspect(|&Pixel(point, color)| {
+                if area.contains(point) {
+                    let Point { x, y } = point - area.top_left;
+                    recorded[y as usize * area.size.width as usize + x as usize] = Some(color);
+                } else {
+                    *outside = true;
+                }
+            }))
+    }
+}
@@ -3,22 +3,54 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 extern crate alloc;
 
+#[cfg(feature = "alloc-stats")]
+pub mod alloc_stats;
 mod backend;
+mod blocks;
+mod capabilities;
 mod colors;
 mod default_font;
+mod dither;
 pub mod error;
+#[cfg(feature = "frame-limiter")]
+mod frame_limiter;
 #[cfg(feature = "framebuffer")]
 mod framebuffer;
+#[cfg(feature = "glyph-cache")]
+mod glyph_cache;
 mod macros;
 pub mod prelude;
+mod raw_font;
+mod scale;
+#[cfg(feature = "shared-display")]
+mod shared;
+#[cfg(feature = "stats")]
+mod stats;
 
 #[cfg(feature = "blink")]
-pub use backend::{BlinkConfig, BlinkTiming};
-pub use backend::{EmbeddedBackend, EmbeddedBackendConfig, TerminalAlignment};
+pub use backend::{BlinkConfig, BlinkStyle};
+pub use backend::{
+    BlinkTiming, ClipShape, EmbeddedBackend, EmbeddedBackendConfig, EmbeddedBackendConfigBuilder,
+    FlushContext, FlushFn, ModifierProfile, ScreensaverFn, SlowBlinkMode, TerminalAlignment,
+};
+#[cfg(feature = "framebuffer")]
+pub use backend::{FlushStrategy, RowFlushFn};
+pub use capabilities::{DisplayCapabilities, InvertFn, ScrollFn};
 pub use colors::ColorTheme;
+pub use dither::{ConversionQuality, Dithering};
 pub use embedded_graphics;
+#[cfg(feature = "frame-limiter")]
+pub use frame_limiter::FrameLimiter;
+#[cfg(feature = "framebuffer")]
+pub use framebuffer::{Framebuffer, StaticBuffer};
+pub use raw_font::font_from_raw;
+pub use scale::Rotation;
+#[cfg(feature = "shared-display")]
+pub use shared::SharedDisplay;
+#[cfg(feature = "stats")]
+pub use stats::RenderStats;
 pub mod cursor;
-pub use cursor::{CursorConfig, CursorStyle};
+pub use cursor::{CursorConfig, CursorFn, CursorStyle, CursorTarget};
 
 #[cfg(feature = "fonts")]
 pub use embedded_graphics_unicodefonts as fonts;
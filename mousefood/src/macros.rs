@@ -13,4 +13,15 @@ macro_rules! for_all_rgb_colors {
     };
 }
 
+macro_rules! for_all_gray_colors {
+    (
+        $inner:ident
+    ) => {
+        $inner!(Gray2);
+        $inner!(Gray4);
+        $inner!(Gray8);
+    };
+}
+
+pub(crate) use for_all_gray_colors;
 pub(crate) use for_all_rgb_colors;
@@ -11,7 +11,7 @@
 //! ```
 
 pub use crate::ColorTheme;
-pub use crate::backend::{EmbeddedBackend, EmbeddedBackendConfig};
+pub use crate::backend::{EmbeddedBackend, EmbeddedBackendConfig, FlushContext};
 pub use embedded_graphics::pixelcolor::{
     Bgr555, Bgr565, Bgr666, Bgr888, Rgb555, Rgb565, Rgb666, Rgb888,
 };
@@ -0,0 +1,139 @@
+//! Loading bitmap fonts at runtime.
+//!
+//! [`font_from_raw`] builds a [`MonoFont`] from glyph bitmaps that aren't known at
+//! compile time, e.g. a branded or CJK font read from a filesystem or a flash region
+//! at boot.
+//!
+//! The bitmap uses the layout of the built-in `embedded-graphics` fonts: glyphs are
+//! placed left to right and top to bottom in an image that is `glyphs_per_row` glyphs
+//! wide, stored as 1 bit per pixel with the most significant bit first and each image
+//! row padded to a whole byte. The glyph mapping maps characters to glyph indices.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use mousefood::embedded_graphics::geometry::Size;
+//! use mousefood::embedded_graphics::mono_font::mapping::StrGlyphMapping;
+//! use mousefood::font_from_raw;
+//!
+//! // Two 4×4 glyphs side by side: a filled square and a hollow one.
+//! static GLYPHS: [u8; 4] = [0b1111_1111, 0b1111_1001, 0b1111_1001, 0b1111_1111];
+//! static MAPPING: StrGlyphMapping = StrGlyphMapping::new("#o", 0);
+//!
+//! let font = font_from_raw(&GLYPHS, Size::new(4, 4), 2, &MAPPING)?;
+//! assert_eq!(font.character_size, Size::new(4, 4));
+//! # Ok::<(), mousefood::error::Error>(())
+//! ```
+
+use crate::error::{Error, Result};
+use embedded_graphics::geometry::Size;
+use embedded_graphics::image::ImageRaw;
+use embedded_graphics::mono_font::mapping::GlyphMapping;
+use embedded_graphics::mono_font::{DecorationDimensions, MonoFont};
+
+/// Builds a font from a glyph bitmap and a glyph mapping.
+///
+/// `data` holds the glyph image described in the [module docs](self), with
+/// `glyphs_per_row` glyphs of `character_size` per row of glyphs. The baseline,
+/// underline and strikethrough are placed like in the built-in fonts; adjust the
+/// fields of the returned font if they don't suit it.
+///
+/// Returns [`Error::InvalidFont`] if the glyph size is empty or `data` doesn't hold
+/// whole rows of glyphs. Use [`Box::leak`](alloc::boxed::Box::leak) on loaded data
+/// to get a `MonoFont<'static>` for
+/// [`EmbeddedBackendConfig`](crate::EmbeddedBackendConfig).
+pub fn font_from_raw<'a>(
+    data: &'a [u8],
+    character_size: Size,
+    glyphs_per_row: u32,
+    glyph_mapping: &'a dyn GlyphMapping,
+) -> Result<MonoFont<'a>> {
+    if character_size.width == 0 || character_size.height == 0 || glyphs_per_row == 0 {
+        return Err(Error::InvalidFont("empty glyph size"));
+    }
+    let width = character_size.width * glyphs_per_row;
+    let bytes_per_row = width.div_ceil(8) as usize;
+    let rows = data.len() / bytes_per_row;
+    if !data.len().is_multiple_of(bytes_per_row)
+        || rows == 0
+        || !rows.is_multiple_of(character_size.height as usize)
+    {
+        return Err(Error::InvalidFont("data doesn't hold whole rows of glyphs"));
+    }
+
+    // Matches the metrics of the built-in fonts, e.g. a baseline of 4 for 4x6 and of
+    // 7 for 6x10.
+    let baseline = character_size.height - 1 - character_size.height / 5;
+    Ok(MonoFont {
+        image: ImageRaw::new(data, width),
+        glyph_mapping,
+        character_size,
+        character_spacing: 0,
+        baseline,
+        underline: DecorationDimensions::new(baseline + 2, 1),
+        strikethrough: DecorationDimensions::new(character_size.height / 2, 1),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use embedded_graphics::geometry::Point;
+    use embedded_graphics::mock_display::MockDisplay;
+    use embedded_graphics::mono_font::mapping::StrGlyphMapping;
+    use ratatui_core::backend::Backend;
+    use ratatui_core::buffer::Cell;
+    use ratatui_core::style::Color;
+
+    // A filled square and a hollow one.
+    static GLYPHS: [u8; 4] = [0b1111_1111, 0b1111_1001, 0b1111_1001, 0b1111_1111];
+    static MAPPING: StrGlyphMapping = StrGlyphMapping::new("#o", 0);
+
+    #[test]
+    fn renders_loaded_glyphs() {
+        let font = font_from_raw(&GLYPHS, Size::new(4, 4), 2, &MAPPING).unwrap();
+        let mut display = MockDisplay::<Rgb888>::new();
+        display.set_allow_overdraw(true);
+        let config = EmbeddedBackendConfig {
+            font_regular: font,
+            ..Default::default()
+        };
+        let mut backend = EmbeddedBackend::new(&mut display, config);
+        assert_eq!(backend.size().unwrap().width, 16);
+
+        let mut cells = [Cell::new("#"), Cell::new("o")];
+        for cell in &mut cells {
+            cell.fg = Color::White;
+            cell.bg = Color::Black;
+        }
+        backend
+            .draw(
+                cells
+                    .iter()
+                    .enumerate()
+                    .map(|(x, cell)| (x as u16, 0, cell)),
+            )
+            .unwrap();
+        backend.flush().unwrap();
+
+        let theme = ColorTheme::default();
+        let display = backend.display();
+        let pixel = |x, y| display.get_pixel(Point::new(x, y));
+        assert_eq!(pixel(1, 1), Some(theme.white));
+        assert_eq!(pixel(5, 1), Some(theme.black));
+        assert_eq!(pixel(4, 0), Some(theme.white));
+    }
+
+    #[test]
+    fn rejects_partial_glyph_rows() {
+        assert!(matches!(
+            font_from_raw(&GLYPHS[..3], Size::new(4, 4), 2, &MAPPING),
+            Err(Error::InvalidFont(_))
+        ));
+        assert!(matches!(
+            font_from_raw(&GLYPHS, Size::new(0, 4), 2, &MAPPING),
+            Err(Error::InvalidFont(_))
+        ));
+    }
+}
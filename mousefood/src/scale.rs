@@ -0,0 +1,193 @@
+//! Nearest-neighbor scaling and rotation of drawing operations.
+
+use embedded_graphics::Pixel;
+use embedded_graphics::draw_target::DrawTarget;
+use embedded_graphics::geometry::{Dimensions, Point, Size};
+use embedded_graphics::primitives::{PointsIter, Rectangle};
+
+/// Clockwise rotation of the terminal on the display.
+///
+/// Applied in software on top of the display driver, e.g. for a panel mounted
+/// sideways whose driver can't rotate.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Rotation {
+    /// The terminal is drawn upright.
+    #[default]
+    Deg0,
+    /// The top of the terminal is at the right edge of the display.
+    Deg90,
+    /// The terminal is drawn upside down.
+    Deg180,
+    /// The top of the terminal is at the left edge of the display.
+    Deg270,
+}
+
+impl Rotation {
+    /// Returns whether the rotation swaps width and height.
+    pub(crate) fn swaps_axes(self) -> bool {
+        matches!(self, Rotation::Deg90 | Rotation::Deg270)
+    }
+
+    /// Returns the size of a display of `size` as seen by the terminal.
+    pub(crate) fn rotate_size(self, size: Size) -> Size {
+        if self.swaps_axes() {
+            Size::new(size.height, size.width)
+        } else {
+            size
+        }
+    }
+
+    /// Maps `offset` from the top-left corner of the terminal to the offset from the
+    /// top-left corner of a display of `size`.
+    pub(crate) fn rotate_point(self, offset: Point, size: Size) -> Point {
+        let (w, h) = (size.width as i32, size.height as i32);
+        match self {
+            Rotation::Deg0 => offset,
+            Rotation::Deg90 => Point::new(w - 1 - offset.y, offset.x),
+            Rotation::Deg180 => Point::new(w - 1 - offset.x, h - 1 - offset.y),
+            Rotation::Deg270 => Point::new(offset.y, h - 1 - offset.x),
+        }
+    }
+
+    /// Inverse of [`rotate_point`](Self::rotate_point).
+    pub(crate) fn unrotate_point(self, offset: Point, size: Size) -> Point {
+        let (w, h) = (size.width as i32, size.height as i32);
+        match self {
+            Rotation::Deg0 => offset,
+            Rotation::Deg90 => Point::new(offset.y, w - 1 - offset.x),
+            Rotation::Deg180 => Point::new(w - 1 - offset.x, h - 1 - offset.y),
+            Rotation::Deg270 => Point::new(h - 1 - offset.y, offset.x),
+        }
+    }
+
+    /// Maps `area`, relative to the top-left corner of the terminal, to the area of a
+    /// display of `size` it covers.
+    fn rect_to_display(self, area: &Rectangle, size: Size) -> Rectangle {
+        let Some(bottom_right) = area.bottom_right() else {
+            return Rectangle::new(self.rotate_point(area.top_left, size), Size::zero());
+        };
+        let (a, b) = (
+            self.rotate_point(area.top_left, size),
+            self.rotate_point(bottom_right, size),
+        );
+        Rectangle::with_corners(a, b)
+    }
+}
+
+/// Draw target that scales everything drawn to it around `origin`, after rotating
+/// it by `rotation` within a display of `size` unscaled pixels.
+///
+/// Each pixel becomes a block of `x × y` pixels in the wrapped target.
+pub(crate) struct Scaled<'a, T> {
+    pub(crate) target: &'a mut T,
+    pub(crate) origin: Point,
+    pub(crate) x: u32,
+    pub(crate) y: u32,
+    pub(crate) rotation: Rotation,
+    pub(crate) size: Size,
+}
+
+impl<'a, T> Scaled<'a, T>
+where
+    T: DrawTarget,
+{
+    /// Scales everything drawn by `scale` in both directions and rotates it by
+    /// `rotation`, around the top-left corner of `target`.
+    pub(crate) fn uniform(target: &'a mut T, scale: u32, rotation: Rotation) -> Self {
+        let bounding_box = target.bounding_box();
+        Self {
+            target,
+            origin: bounding_box.top_left,
+            x: scale,
+            y: scale,
+            rotation,
+            size: bounding_box.size / scale,
+        }
+    }
+
+    /// Returns the area of the wrapped target covered by `area`.
+    pub(crate) fn scale_rect(&self, area: &Rectangle) -> Rectangle {
+        scale_rect(
+            area,
+            self.origin,
+            (self.x, self.y),
+            self.rotation,
+            self.size,
+        )
+    }
+}
+
+/// Returns the area of a display with the top-left corner `origin` covered by
+/// `area`, after rotating it within `size` unscaled pixels and scaling it.
+pub(crate) fn scale_rect(
+    area: &Rectangle,
+    origin: Point,
+    (x, y): (u32, u32),
+    rotation: Rotation,
+    size: Size,
+) -> Rectangle {
+    let area = rotation.rect_to_display(&Rectangle::new(area.top_left - origin, area.size), size);
+    Rectangle::new(
+        origin + Point::new(area.top_left.x * x as i32, area.top_left.y * y as i32),
+        Size::new(area.size.width * x, area.size.height * y),
+    )
+}
+
+impl<T> Dimensions for Scaled<'_, T>
+where
+    T: DrawTarget,
+{
+    fn bounding_box(&self) -> Rectangle {
+        match self.rotation {
+            Rotation::Deg0 => self.target.bounding_box(),
+            rotation => Rectangle::new(self.origin, rotation.rotate_size(self.size)),
+        }
+    }
+}
+
+impl<T> DrawTarget for Scaled<'_, T>
+where
+    T: DrawTarget,
+{
+    type Color = T::Color;
+    type Error = T::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let (origin, sx, sy) = (self.origin, self.x as i32, self.y as i32);
+        let (rotation, size) = (self.rotation, self.size);
+        self.target
+            .draw_iter(pixels.into_iter().flat_map(move |Pixel(point, color)| {
+                let offset = rotation.rotate_point(point - origin, size);
+                let top_left = origin + Point::new(offset.x * sx, offset.y * sy);
+                (0..sy).flat_map(move |dy| {
+                    (0..sx).map(move |dx| Pixel(top_left + Point::new(dx, dy), color))
+                })
+            }))
+    }
+
+    fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        if (self.x, self.y, self.rotation) == (1, 1, Rotation::Deg0) {
+            return self.target.fill_contiguous(area, colors);
+        }
+        self.draw_iter(
+            area.points()
+                .zip(colors)
+                .map(|(point, color)| Pixel(point, color)),
+        )
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        let area = self.scale_rect(area);
+        self.target.fill_solid(&area, color)
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        self.target.clear(color)
+    }
+}
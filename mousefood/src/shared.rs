@@ -0,0 +1,120 @@
+//! Sharing a display between mousefood and other drawing tasks.
+//!
+//! [`SharedDisplay`] wraps a display stored in a [`critical_section::Mutex`] and
+//! implements [`DrawTarget`] by locking it for the duration of every draw call.
+//! Pass it to [`EmbeddedBackend::new`](crate::EmbeddedBackend::new) in place of the
+//! display itself, while other tasks lock the same mutex to draw in between frames.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use core::cell::RefCell;
+//! use critical_section::Mutex;
+//! use mousefood::embedded_graphics::{mock_display::MockDisplay, pixelcolor::Rgb888};
+//! use mousefood::prelude::*;
+//! use mousefood::SharedDisplay;
+//!
+//! // Typically a `static`, shared with the other tasks.
+//! let display = Box::leak(Box::new(Mutex::new(RefCell::new(MockDisplay::<Rgb888>::new()))));
+//! let mut shared = SharedDisplay::new(display);
+//! let _backend = EmbeddedBackend::new(&mut shared, EmbeddedBackendConfig::default());
+//! ```
+
+use core::cell::RefCell;
+use critical_section::Mutex;
+use embedded_graphics::Pixel;
+use embedded_graphics::draw_target::DrawTarget;
+use embedded_graphics::geometry::Dimensions;
+use embedded_graphics::primitives::Rectangle;
+
+/// A [`DrawTarget`] forwarding to a display guarded by a [`critical_section::Mutex`].
+pub struct SharedDisplay<'a, D> {
+    display: &'a Mutex<RefCell<D>>,
+}
+
+impl<'a, D> SharedDisplay<'a, D> {
+    /// Wraps a reference to the shared display.
+    pub fn new(display: &'a Mutex<RefCell<D>>) -> Self {
+        Self { display }
+    }
+
+    /// Locks the display and runs `f` with exclusive access to it.
+    pub fn with<R>(&self, f: impl FnOnce(&mut D) -> R) -> R {
+        critical_section::with(|cs| f(&mut self.display.borrow_ref_mut(cs)))
+    }
+}
+
+impl<D: Dimensions> Dimensions for SharedDisplay<'_, D> {
+    fn bounding_box(&self) -> Rectangle {
+        self.with(|display| display.bounding_box())
+    }
+}
+
+impl<D: DrawTarget> DrawTarget for SharedDisplay<'_, D> {
+    type Color = D::Color;
+    type Error = D::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        self.with(|display| display.draw_iter(pixels))
+    }
+
+    fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        self.with(|display| display.fill_contiguous(area, colors))
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        self.with(|display| display.fill_solid(area, color))
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        self.with(|display| display.clear(color))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use embedded_graphics::geometry::Point;
+    use embedded_graphics::mock_display::MockDisplay;
+    use embedded_graphics::mono_font::ascii::FONT_4X6;
+    use embedded_graphics::pixelcolor::RgbColor;
+    use ratatui::Terminal;
+
+    #[test]
+    fn renders_through_mutex() {
+        let display: &'static _ = alloc::boxed::Box::leak(alloc::boxed::Box::new(Mutex::new(
+            RefCell::new(MockDisplay::<Rgb888>::new()),
+        )));
+        critical_section::with(|cs| display.borrow_ref_mut(cs).set_allow_overdraw(true));
+
+        let mut shared = SharedDisplay::new(display);
+        let config = EmbeddedBackendConfig {
+            font_regular: FONT_4X6,
+            ..Default::default()
+        };
+        let backend = EmbeddedBackend::new(&mut shared, config);
+        let mut terminal = Terminal::new(backend).expect("to create terminal");
+        terminal
+            .draw(|frame| {
+                use ratatui::style::Stylize;
+                use ratatui::text::Line;
+                frame.render_widget(Line::from(" ".on_red()), frame.area());
+            })
+            .expect("to draw");
+        drop(terminal);
+
+        // Another task can draw into the display in between frames.
+        let other = SharedDisplay::new(display);
+        other.with(|d| {
+            assert_eq!(d.get_pixel(Point::new(0, 0)), Some(Rgb888::RED));
+            assert_eq!(d.get_pixel(Point::new(3, 5)), Some(Rgb888::RED));
+        });
+    }
+}
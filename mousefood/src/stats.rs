@@ -0,0 +1,23 @@
+//! Per-frame render statistics.
+
+/// Rendering work performed during a frame.
+///
+/// A frame spans from the start of [`Backend::draw`] to the end of
+/// [`Backend::flush`]. Returned by
+/// [`EmbeddedBackend::last_frame_stats`](crate::EmbeddedBackend::last_frame_stats).
+///
+/// [`Backend::draw`]: ratatui_core::backend::Backend::draw
+/// [`Backend::flush`]: ratatui_core::backend::Backend::flush
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct RenderStats {
+    /// Cells that were rendered, either on their own or as part of a run of blank
+    /// cells.
+    pub cells_drawn: u32,
+    /// Cells that were passed to `draw` but not rendered, because they are outside
+    /// the visible area or already on screen.
+    pub cells_skipped: u32,
+    /// Solid fills of cell areas, e.g. runs of blank cells or underlines.
+    pub fill_solid_calls: u32,
+    /// Text draws of cell symbols.
+    pub glyph_draws: u32,
+}
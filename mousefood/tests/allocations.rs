@@ -0,0 +1,64 @@
+//! Steady-state frames must not touch the heap.
+//!
+//! Lives in its own test binary so that the counting global allocator only sees
+//! allocations made by this single test.
+#![cfg(feature = "alloc-stats")]
+
+use mousefood::alloc_stats::CountingAllocator;
+use mousefood::embedded_graphics::mock_display::MockDisplay;
+use mousefood::embedded_graphics::mono_font::ascii::FONT_4X6;
+use mousefood::prelude::*;
+use ratatui::Terminal;
+use ratatui::style::{Modifier, Style};
+use ratatui::widgets::Paragraph;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator<std::alloc::System> =
+    CountingAllocator::new(std::alloc::System);
+
+#[test]
+fn steady_state_frame_does_not_allocate() {
+    assert_eq!(
+        steady_state_allocations(EmbeddedBackendConfig::default(), Style::new()),
+        0
+    );
+    // The underline of FONT_4X6 lies below the cell, so the pixels it covers are
+    // saved on every redraw.
+    let config = EmbeddedBackendConfig {
+        font_regular: FONT_4X6,
+        ..Default::default()
+    };
+    let underlined = Style::new().add_modifier(Modifier::UNDERLINED);
+    assert_eq!(steady_state_allocations(config, underlined), 0);
+}
+
+/// Returns the heap allocations of the last of a few frames drawing changing text.
+///
+/// The configurations are checked one after the other in a single test, as the
+/// allocation counter is shared by all threads.
+fn steady_state_allocations(
+    config: EmbeddedBackendConfig<MockDisplay<Rgb888>, Rgb888>,
+    style: Style,
+) -> usize {
+    let mut display = MockDisplay::<Rgb888>::new();
+    display.set_allow_overdraw(true);
+    let config = EmbeddedBackendConfig {
+        // Each frame shows a new digit, which the glyph cache would allocate for.
+        #[cfg(feature = "glyph-cache")]
+        glyph_cache_size: 0,
+        ..config
+    };
+    let backend = EmbeddedBackend::new(&mut display, config);
+    let mut terminal = Terminal::new(backend).unwrap();
+
+    for frame in 0..4 {
+        terminal
+            .draw(|f| {
+                let text = Paragraph::new(format!("frame {frame}")).style(style);
+                f.render_widget(text, f.area())
+            })
+            .unwrap();
+    }
+
+    terminal.backend().frame_allocations()
+}